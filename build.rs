@@ -0,0 +1,73 @@
+//! Generates the module list of the `all` binary from the solutions in `src/bin` and its year directories.
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+fn is_number(s: &str, len: usize) -> bool {
+    s.len() == len && s.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Paths of the solutions in a directory, keyed by day.
+fn find_days(dir: &Path) -> Vec<(String, PathBuf)> {
+    let mut days: Vec<(String, PathBuf)> = fs::read_dir(dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let stem = path.file_name()?.to_str()?.strip_suffix(".rs")?;
+            is_number(stem, 2).then(|| (stem.to_string(), path.clone()))
+        })
+        .collect();
+    days.sort();
+    days
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let bin_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src/bin");
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions.rs");
+
+    // solutions of the default year, followed by the solutions in year directories like `src/bin/2022`.
+    let mut modules: Vec<(String, PathBuf)> = find_days(&bin_dir)
+        .into_iter()
+        .map(|(day, path)| (format!("day_{day}"), path))
+        .collect();
+
+    let mut years: Vec<PathBuf> = fs::read_dir(&bin_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            (path.is_dir() && is_number(path.file_name()?.to_str()?, 4)).then_some(path)
+        })
+        .collect();
+    years.sort();
+
+    for year_dir in &years {
+        let year = year_dir.file_name().unwrap().to_str().unwrap();
+        for (day, path) in find_days(year_dir) {
+            modules.push((format!("y{year}_day_{day}"), path));
+        }
+    }
+
+    let mut code = String::new();
+
+    for (module, path) in &modules {
+        // every day is linted as its own binary already.
+        code.push_str(&format!(
+            "#[allow(warnings)]\n#[path = {path:?}]\nmod {module};\n"
+        ));
+    }
+
+    code.push_str("\n/// Returns the registry of all solutions in `src/bin`.\n");
+    code.push_str("fn solutions() -> advent_of_code::template::registry::Solutions {\n");
+    code.push_str(
+        "    let mut solutions = advent_of_code::template::registry::Solutions::new();\n",
+    );
+    for (module, _) in &modules {
+        code.push_str(&format!("    solutions.register({module}::SOLUTION);\n"));
+    }
+    code.push_str("    solutions\n}\n");
+
+    fs::write(out_path, code).unwrap();
+}
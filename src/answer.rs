@@ -0,0 +1,252 @@
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+
+use num::{BigInt, BigUint};
+
+use crate::template::json::Json;
+use crate::template::ocr;
+
+/// The answer of a puzzle part. It keeps the text the solution produced, which is what gets displayed,
+/// submitted and recorded. Answers are compared by a normalised form, so that they are equal regardless
+/// of how they were produced:
+///
+/// - integers of any size lose a leading `+` and leading zeros, e.g. `+042` equals `42`.
+/// - single-line answers are trimmed.
+/// - multi-line answers (e.g. letters drawn with `#`) use `\n` line breaks and lose trailing whitespace
+///   and surrounding blank lines, but keep their indentation.
+///
+/// Multi-line answers that draw letters are read with [`Answer::letters`], which is what gets submitted.
+///
+/// Solutions can return integers, big integers, strings and chars as their answer.
+/// Other types can be converted with [`Answer::new`], which uses their [`Display`] output.
+///
+/// ```
+/// # use advent_of_code::Answer;
+/// assert_eq!(Answer::from(42_u64), Answer::from(" 42\n"));
+/// assert_eq!(Answer::new("#..#  \r\n#..#\n"), "#..#\n#..#");
+/// assert_eq!(Answer::from("0042").as_str(), "0042");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Answer {
+    text: String,
+    /// The normalised text that answers are compared by.
+    normalized: String,
+}
+
+impl Answer {
+    /// Creates an [`Answer`] from any value that displays as the answer.
+    pub fn new(value: impl Display) -> Self {
+        Self::from(value.to_string())
+    }
+
+    /// The text of the answer as the solution produced it.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        self.normalized.contains('\n')
+    }
+
+    /// The text of a multi-line answer that draws letters in one of the advent of code fonts.
+    pub fn letters(&self) -> Option<Answer> {
+        if !self.is_multiline() {
+            return None;
+        }
+        ocr::decode(&self.normalized).map(Self::from)
+    }
+
+    /// Whether the answer equals the confirmed answer `expected`, either as is or by the letters it draws.
+    pub fn matches(&self, expected: &Answer) -> bool {
+        self == expected || self.letters().is_some_and(|letters| &letters == expected)
+    }
+
+    /// The value of an integer answer, if it fits into an [`i128`].
+    pub fn as_integer(&self) -> Option<i128> {
+        self.normalized.parse().ok()
+    }
+
+    pub fn to_json(&self) -> Json {
+        self.as_str().into()
+    }
+
+    pub fn from_json(value: &Json) -> Option<Self> {
+        value.as_str().map(Self::from)
+    }
+}
+
+fn normalize(s: &str) -> String {
+    let lines: Vec<&str> = s
+        .lines()
+        .map(str::trim_end)
+        .skip_while(|line| line.is_empty())
+        .collect();
+
+    let end = lines
+        .iter()
+        .rposition(|line| !line.is_empty())
+        .map_or(0, |i| i + 1);
+
+    match &lines[..end] {
+        [] => String::new(),
+        [line] => normalize_integer(line.trim()).unwrap_or_else(|| line.trim().to_string()),
+        lines => lines.join("\n"),
+    }
+}
+
+/// Normalises integers without parsing them, so that big integers keep their precision.
+fn normalize_integer(s: &str) -> Option<String> {
+    let (sign, digits) = match s.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", s.strip_prefix('+').unwrap_or(s)),
+    };
+
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let digits = digits.trim_start_matches('0');
+    Some(if digits.is_empty() {
+        "0".into()
+    } else {
+        format!("{sign}{digits}")
+    })
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        self.normalized == other.normalized
+    }
+}
+
+impl Eq for Answer {}
+
+impl Hash for Answer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.normalized.hash(state);
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// Every other conversion goes through this one, so that all answers are normalised the same way.
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        let normalized = normalize(&text);
+        Self { text, normalized }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Self::from(s.to_string())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(c: char) -> Self {
+        Self::new(c)
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Self::new(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, BigInt, BigUint);
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        self.normalized == normalize(other)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<String> for Answer {
+    fn eq(&self, other: &String) -> bool {
+        self == other.as_str()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Answer;
+    use num::BigInt;
+
+    #[test]
+    fn normalizes_integers() {
+        assert_eq!(Answer::from(42_u32), "42");
+        assert_eq!(Answer::from(-7_i64), "-7");
+        assert_eq!(Answer::from("+0042"), "42");
+        assert_eq!(Answer::from("-0"), "0");
+        assert_eq!(Answer::from(7_u8), Answer::from("007"));
+        assert_eq!(Answer::from(u128::MAX).as_integer(), None);
+
+        let big = BigInt::from(u128::MAX) + 1;
+        assert_eq!(
+            Answer::from(big),
+            "000340282366920938463463374607431768211456"
+        );
+    }
+
+    #[test]
+    fn keeps_the_produced_text() {
+        let answer = Answer::from("01029498");
+        assert_eq!(answer.as_str(), "01029498");
+        assert_eq!(answer.to_string(), "01029498");
+        assert_eq!(answer, Answer::from(1_029_498_u32));
+        assert_eq!(answer.as_integer(), Some(1_029_498));
+        assert_eq!(
+            Answer::from_json(&answer.to_json()).unwrap().as_str(),
+            "01029498"
+        );
+    }
+
+    #[test]
+    fn normalizes_strings() {
+        assert_eq!(Answer::from("  abc\n"), "abc");
+        assert_eq!(Answer::from("1,2,3"), "1,2,3");
+        assert_ne!(Answer::from("abc"), "ABC");
+        assert_eq!(Answer::from(""), "");
+    }
+
+    #[test]
+    fn normalizes_multiline_answers() {
+        let answer = Answer::new("\n#..#   \r\n #.#\n\n");
+        assert_eq!(answer.as_str(), "\n#..#   \r\n #.#\n\n");
+        assert!(answer.is_multiline());
+        assert_eq!(answer, "#..#\n #.#\n");
+        assert_eq!(answer.letters(), None);
+    }
+
+    #[test]
+    fn matches_drawn_letters() {
+        let answer = Answer::new("#..#.###\n#..#..#.\n####..#.\n#..#..#.\n#..#..#.\n#..#.###");
+        assert_eq!(answer.letters(), Some("HI".into()));
+        assert!(answer.matches(&"HI".into()));
+        assert!(answer.matches(&answer.clone()));
+        assert!(!answer.matches(&"HL".into()));
+        assert_eq!(Answer::from("HI").letters(), None);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
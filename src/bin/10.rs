@@ -1,4 +1,4 @@
-use std::iter;advent_of_code::solution!(10);
+use std::iter;advent_of_code::solution!(10, parsed);
 
 
 fn pairwise<I>(right: I) -> impl Iterator<Item = (Option<I::Item>, I::Item)>
@@ -76,21 +76,23 @@ fn walk(map: &Vec<Vec<char>>) -> (Vec<Vec<i32>>, i32){
     (visits_map, counter)
 }
 
-pub fn part_one(input: &str) -> Option<i32> {
+pub fn parse(input: &str) -> (Vec<Vec<i32>>, i32) {
     let map: Vec<Vec<char>> = input.lines().map(|s| s.chars().collect::<Vec<char>>()).collect();
-    let (visits_map, counter) = walk(&map);
+    let (mut visits_map, counter) = walk(&map);
+    visits_map.iter_mut().for_each(|v| v.sort());
+    (visits_map, counter)
+}
+
+pub fn part_one((_, counter): &(Vec<Vec<i32>>, i32)) -> Option<i32> {
     Some(counter / 2)
 }
 
-pub fn part_two(input: &str) -> Option<i32> {
-    let map: Vec<Vec<char>> = input.lines().map(|s| s.chars().collect::<Vec<char>>()).collect();
-    let (mut visits_map, counter) = walk(&map);
+pub fn part_two((visits_map, _): &(Vec<Vec<i32>>, i32)) -> Option<i32> {
     let result = visits_map
-        .iter_mut()
+        .iter()
         .enumerate()
         .filter(|(ind, v)| v.len() > 0)
-        .map(|(ind, mut v)| {
-            v.sort();
+        .map(|(ind, v)| {
             pairwise(v.iter())
                 .skip(1)
                 .map(|(a, b)| { b - a.unwrap() - 1})
@@ -108,13 +110,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse(&advent_of_code::template::read_file("examples", DAY)));
         assert_eq!(result, Some(6815));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse(&advent_of_code::template::read_file("examples", DAY)));
         assert_eq!(result, None);
     }
 }
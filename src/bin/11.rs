@@ -3,7 +3,7 @@
 use std::cmp::min;
 use std::collections::VecDeque;
 use itertools::Itertools;
-advent_of_code::solution!(11);
+advent_of_code::solution!(11, combined);
 
 
 fn find_star_positions(desk: &VecDeque<VecDeque<char>>) -> VecDeque<(usize, usize)> {
@@ -47,8 +47,11 @@ fn calculate_y_distances(map: &VecDeque<VecDeque<char>>, scale_factor: usize) ->
 }
 
 
-fn calculate_distance_sums(map: &VecDeque<VecDeque<char>>, scale_factor: usize) -> i64 {
-    let star_positions = find_star_positions(map);
+fn calculate_distance_sums(
+    map: &VecDeque<VecDeque<char>>,
+    star_positions: &VecDeque<(usize, usize)>,
+    scale_factor: usize
+) -> i64 {
     let x_dist = calculate_x_distances(map, scale_factor);
     let y_dist = calculate_y_distances(map, scale_factor);
     star_positions.iter().combinations(2).map(
@@ -62,24 +65,18 @@ fn calculate_distance_sums(map: &VecDeque<VecDeque<char>>, scale_factor: usize)
     ).sum()
 }
 
-pub fn part_one(input: &str) -> Option<i64> {
+pub fn solve(input: &str) -> (Option<i64>, Option<i64>) {
     let map: VecDeque<VecDeque<char>> = input
         .lines()
         .map(|s| s.chars().collect::<VecDeque<char>>())
         .collect();
 
-    let dist_sum = calculate_distance_sums(&map, 2);
-    Some(dist_sum)
-}
-
-pub fn part_two(input: &str) -> Option<i64> {
-    let map: VecDeque<VecDeque<char>> = input
-        .lines()
-        .map(|s| s.chars().collect::<VecDeque<char>>())
-        .collect();
-
-    let dist_sum = calculate_distance_sums(&map, 1000000);
-    Some(dist_sum)
+    let star_positions = find_star_positions(&map);
+    let factor = advent_of_code::template::params::get("factor", 1000000);
+    (
+        Some(calculate_distance_sums(&map, &star_positions, 2)),
+        Some(calculate_distance_sums(&map, &star_positions, factor)),
+    )
 }
 
 
@@ -89,13 +86,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let (result, _) = solve(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, Some(9445168));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let (_, result) = solve(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, Some(742305960572));
     }
 }
@@ -258,13 +258,16 @@ impl <'a> TriggerGrid <'a> {
 pub fn part_one(input: &str) -> Option<i128> {
     let rules = input.lines().collect::<Vec<&str>>();
     let mut grid = TriggerGrid::new(rules).unwrap();
-    Some(grid.run_one(1000))
+    let presses = advent_of_code::template::params::get("presses", 1000);
+    Some(grid.run_one(presses))
 }
 
 pub fn part_two(input: &str) -> Option<i64> {
     let rules = input.lines().collect::<Vec<&str>>();
     let mut grid = TriggerGrid::new(rules).unwrap();
-    let feed_map = grid.run_two(6000, "tj").unwrap();
+    let iterations = advent_of_code::template::params::get("iterations", 6000);
+    let feed = advent_of_code::template::params::get("feed", String::from("tj"));
+    let feed_map = grid.run_two(iterations, &feed).unwrap();
     feed_map.values().map(|&a| a).reduce(|a, b| a.lcm(&b))
 }
 
@@ -0,0 +1,10 @@
+//! Runs every solution in `src/bin` in a single process, see `cargo all`.
+//! The list of solutions is generated by `build.rs`.
+
+#[cfg(not(test))]
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+#[cfg(not(test))]
+fn main() {
+    advent_of_code::template::commands::all::run(&solutions());
+}
@@ -2,7 +2,13 @@ use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
+use crate::Year;
+
+/// The last day of the longest calendar.
+const MAX_DAY: u8 = 25;
+
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// Calendars of some years are shorter, see [`Year::day_count`].
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -19,12 +25,32 @@ impl Day {
     /// Creates a [`Day`] from the provided value if it's in the valid range,
     /// returns [`None`] otherwise.
     pub fn new(day: u8) -> Option<Self> {
-        if day == 0 || day > 25 {
+        if day == 0 || day > MAX_DAY {
             return None;
         }
         Some(Self(day))
     }
 
+    /// Creates a [`Day`] from the provided value if it's part of the calendar of `year`,
+    /// returns [`None`] otherwise.
+    pub fn new_in(year: Year, day: u8) -> Option<Self> {
+        Self::new(day).filter(|day| day.is_in(year))
+    }
+
+    /// Whether the day is part of the calendar of `year`.
+    pub fn is_in(self, year: Year) -> bool {
+        self.0 <= year.day_count()
+    }
+
+    /// Parses a [`Day`] that is part of the calendar of `year`.
+    pub fn parse_in(s: &str, year: Year) -> Result<Self, DayFromStrError> {
+        let error = DayFromStrError {
+            last: year.day_count(),
+        };
+        let day = s.parse().map_err(|_| error)?;
+        Self::new_in(year, day).ok_or(error)
+    }
+
     // Not part of the public API
     #[doc(hidden)]
     pub const fn __new_unchecked(day: u8) -> Self {
@@ -61,39 +87,45 @@ impl FromStr for Day {
     type Err = DayFromStrError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let day = s.parse().map_err(|_| DayFromStrError)?;
-        Self::new(day).ok_or(DayFromStrError)
+        let error = DayFromStrError { last: MAX_DAY };
+        let day = s.parse().map_err(|_| error)?;
+        Self::new(day).ok_or(error)
     }
 }
 
 /// An error which can be returned when parsing a [`Day`].
-#[derive(Debug)]
-pub struct DayFromStrError;
+#[derive(Debug, Clone, Copy)]
+pub struct DayFromStrError {
+    last: u8,
+}
 
 impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("expecting a day number between 1 and 25")
+        write!(f, "expecting a day number between 1 and {}", self.last)
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
-pub fn all_days() -> AllDays {
-    AllDays::new()
+/// An iterator that yields every day of advent of a year, from the 1st to its last day.
+pub fn all_days(year: Year) -> AllDays {
+    AllDays::new(year)
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent of a year, from the 1st to its last day.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> Self {
-        Self { current: 1 }
+    pub fn new(year: Year) -> Self {
+        Self {
+            current: 1,
+            last: year.day_count(),
+        }
     }
 }
 
@@ -101,10 +133,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
+        // NOTE: the iterator starts at 1 and we have verified that the value is not above the last day.
         let day = Day(self.current);
         self.current += 1;
 
@@ -135,10 +167,11 @@ macro_rules! day {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{all_days, Day};
+    use crate::year;
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = all_days(year!(2023));
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
@@ -167,6 +200,18 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn short_calendar() {
+        let year = year!(2025);
+        assert_eq!(all_days(year).last(), Some(Day(12)));
+        assert_eq!(Day::new_in(year, 13), None);
+        assert_eq!(
+            Day::parse_in("13", year).unwrap_err().to_string(),
+            "expecting a day number between 1 and 12"
+        );
+        assert_eq!(Day::parse_in("12", year).ok(), Some(Day(12)));
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -1,4 +1,10 @@
+mod answer;
 mod day;
+mod part;
 pub mod template;
+mod year;
 
+pub use answer::*;
 pub use day::*;
+pub use part::*;
+pub use year::*;
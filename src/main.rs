@@ -1,10 +1,18 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{
+    all, bench_diff, download, examples, read, scaffold, solve, stars, status, verify,
+};
 use args::{parse, AppArguments};
 
 mod args {
     use std::process;
 
-    use advent_of_code::Day;
+    use advent_of_code::template::bench::{self, BenchOptions};
+    use advent_of_code::template::bench_history;
+    use advent_of_code::template::commands::solve;
+    use advent_of_code::template::report::Format;
+    use advent_of_code::template::timeout::Timeouts;
+    use advent_of_code::template::{params, paths};
+    use advent_of_code::{Day, Year};
 
     pub enum AppArguments {
         Download {
@@ -13,43 +21,85 @@ mod args {
         Read {
             day: Day,
         },
+        Examples {
+            day: Day,
+        },
         Scaffold {
             day: Day,
         },
         Solve {
             day: Day,
-            release: bool,
-            time: bool,
-            submit: Option<u8>,
+            options: solve::Options,
         },
         All {
             release: bool,
-            time: bool,
+            time: Option<BenchOptions>,
+            format: Format,
+            jobs: Option<usize>,
+            timeouts: Timeouts,
+        },
+        Stars,
+        Status,
+        Verify {
+            release: bool,
+            jobs: Option<usize>,
+        },
+        BenchDiff {
+            baseline: Option<String>,
+            threshold: f64,
         },
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+    /// Parses the command and the year it applies to, given by `--year` or `AOC_YEAR`.
+    pub fn parse() -> Result<(Year, AppArguments), Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+
+        let year = args
+            .opt_value_from_str("--year")?
+            .or_else(paths::default_year)
+            .ok_or("no year given. Pass `--year` or set AOC_YEAR in \".cargo/config.toml\".")?;
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
                 release: args.contains("--release"),
-                time: args.contains("--time"),
+                time: parse_bench_options(&mut args)?,
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                jobs: args.opt_value_from_str("--jobs")?,
+                timeouts: parse_timeouts(&mut args)?,
+            },
+            Some("bench-diff") => AppArguments::BenchDiff {
+                baseline: args.opt_value_from_str("--baseline")?,
+                threshold: args
+                    .opt_value_from_fn("--threshold", |s| {
+                        s.trim_end_matches('%')
+                            .parse::<f64>()
+                            .map_err(|_| "expecting a percentage like `10` or `10%`")
+                    })?
+                    .unwrap_or(bench_history::DEFAULT_THRESHOLD),
+            },
+            Some("stars") => AppArguments::Stars,
+            Some("status") => AppArguments::Status,
+            Some("verify") => AppArguments::Verify {
+                release: args.contains("--release"),
+                jobs: args.opt_value_from_str("--jobs")?,
             },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                day: parse_day(&mut args, year)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                day: parse_day(&mut args, year)?,
+            },
+            Some("examples") => AppArguments::Examples {
+                day: parse_day(&mut args, year)?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                day: parse_day(&mut args, year)?,
             },
             Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                time: args.contains("--time"),
+                day: parse_day(&mut args, year)?,
+                options: parse_solve_options(&mut args)?,
             },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
@@ -61,12 +111,105 @@ mod args {
             }
         };
 
-        let remaining = args.finish();
+        // `cargo solve 11 -- --param factor=10` leaves the separator behind.
+        let remaining: Vec<_> = args.finish().into_iter().filter(|x| x != "--").collect();
         if !remaining.is_empty() {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
         }
 
-        Ok(app_args)
+        Ok((year, app_args))
+    }
+
+    /// Parses the day argument, which has to be part of the calendar of the year.
+    fn parse_day(
+        args: &mut pico_args::Arguments,
+        year: Year,
+    ) -> Result<Day, Box<dyn std::error::Error>> {
+        let day: String = args.free_from_str()?;
+        Ok(Day::parse_in(&day, year)?)
+    }
+
+    /// Parses the flags of `solve`. `--part` and `--submit` have to agree if both are given,
+    /// and `--submit` does not work with `--input` or `--param`.
+    fn parse_solve_options(
+        args: &mut pico_args::Arguments,
+    ) -> Result<solve::Options, Box<dyn std::error::Error>> {
+        let options = solve::Options {
+            release: args.contains("--release"),
+            part: args.opt_value_from_str("--part")?,
+            submit: args.opt_value_from_str("--submit")?,
+            time: parse_bench_options(args)?,
+            format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+            params: args.values_from_fn("--param", params::parse_param)?,
+            input: args.opt_value_from_str("--input")?,
+            timeouts: parse_timeouts(args)?,
+        };
+
+        if let (Some(part), Some(submit)) = (options.part, options.submit) {
+            if part != submit {
+                return Err(
+                    format!("cannot submit part {submit} while only part {part} runs.").into(),
+                );
+            }
+        }
+
+        if let (Some(input), Some(submit)) = (&options.input, options.submit) {
+            return Err(format!(
+                "cannot submit part {submit} of input {input}, only answers of the day's input are submitted."
+            )
+            .into());
+        }
+
+        if let (false, Some(submit)) = (options.params.is_empty(), options.submit) {
+            return Err(format!(
+                "cannot submit part {submit} computed with parameters, only answers of the puzzle itself are submitted."
+            )
+            .into());
+        }
+
+        Ok(options)
+    }
+
+    /// Parses `--timeout <duration>` for a whole day and `--part-timeout <duration>` for each part.
+    fn parse_timeouts(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Timeouts, Box<dyn std::error::Error>> {
+        let mut timeout = |name: &'static str| {
+            args.opt_value_from_fn(name, |s| {
+                bench::parse_duration(s).ok_or("expecting a duration like `500ms` or `30s`")
+            })
+        };
+
+        Ok(Timeouts {
+            day: timeout("--timeout")?,
+            part: timeout("--part-timeout")?,
+        })
+    }
+
+    /// Parses `--time` together with the optional `--time-budget <duration>` and `--samples <n>`.
+    fn parse_bench_options(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<BenchOptions>, Box<dyn std::error::Error>> {
+        let time = args.contains("--time");
+        let budget = args.opt_value_from_fn("--time-budget", |s| {
+            bench::parse_duration(s).ok_or("expecting a duration like `500ms` or `2s`")
+        })?;
+        let samples = args.opt_value_from_fn("--samples", |s| match s.parse::<u32>() {
+            Ok(0) | Err(_) => Err("expecting a number of samples greater than zero"),
+            Ok(samples) => Ok(samples),
+        })?;
+
+        if !time {
+            if budget.is_some() || samples.is_some() {
+                eprintln!("Warning: `--time-budget` and `--samples` require `--time`.");
+            }
+            return Ok(None);
+        }
+
+        Ok(Some(BenchOptions {
+            budget: budget.unwrap_or(bench::DEFAULT_BUDGET),
+            samples,
+        }))
     }
 }
 
@@ -76,17 +219,26 @@ fn main() {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
-        Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
-            AppArguments::Solve {
-                day,
+        Ok((year, args)) => match args {
+            AppArguments::All {
                 release,
                 time,
-                submit,
-            } => solve::handle(day, release, time, submit),
+                format,
+                jobs,
+                timeouts,
+            } => all::handle(year, release, time, format, jobs, timeouts),
+            AppArguments::Stars => stars::handle(year),
+            AppArguments::Status => status::handle(year),
+            AppArguments::Verify { release, jobs } => verify::handle(year, release, jobs),
+            AppArguments::BenchDiff {
+                baseline,
+                threshold,
+            } => bench_diff::handle(year, baseline, threshold),
+            AppArguments::Download { day } => download::handle(year, day),
+            AppArguments::Read { day } => read::handle(year, day),
+            AppArguments::Examples { day } => examples::handle(year, day),
+            AppArguments::Scaffold { day } => scaffold::handle(year, day),
+            AppArguments::Solve { day, options } => solve::handle(year, day, options),
         },
     };
 }
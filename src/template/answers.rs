@@ -0,0 +1,165 @@
+/// Module that keeps the confirmed answers of a day in `data/answers/{day}.txt` (`data/{year}/answers` for other years), one line per part:
+///
+/// ```text
+/// part 1: 54597
+/// part 2: 54504
+/// ```
+///
+/// Line breaks of multi-line answers are stored as `\n`.
+use std::{fmt::Display, fs, io, path::Path};
+
+use crate::template::paths;
+use crate::{Answer, Day, Part, Year};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse answers: {e}"),
+            Error::IO(e) => write!(f, "could not access answers: {e}"),
+        }
+    }
+}
+
+/// The result of comparing a computed answer with the confirmed one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Correct,
+    Wrong { expected: Answer },
+    Unknown,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_1: Option<Answer>,
+    pub part_2: Option<Answer>,
+}
+
+impl Answers {
+    /// Loads the answers of a day. Returns empty answers if none are known yet.
+    pub fn load(year: Year, day: Day) -> Result<Self, Error> {
+        let path = get_path(year, day);
+        if !Path::new(&path).exists() {
+            return Ok(Self::default());
+        }
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn save(&self, year: Year, day: Day) -> Result<(), Error> {
+        fs::create_dir_all(format!("{}/answers", paths::data_dir(year)))?;
+        fs::write(get_path(year, day), self.serialize())?;
+        Ok(())
+    }
+
+    pub fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part_1.as_ref(),
+            Part::Two => self.part_2.as_ref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: Answer) {
+        match part {
+            Part::One => self.part_1 = Some(answer),
+            Part::Two => self.part_2 = Some(answer),
+        }
+    }
+
+    /// Takes over the known answers of `other`. Returns `true` if an answer changed.
+    pub fn merge(&mut self, other: &Answers) -> bool {
+        let mut changed = false;
+        for part in Part::ALL {
+            if let Some(answer) = other.get(part) {
+                if self.get(part) != Some(answer) {
+                    self.set(part, answer.clone());
+                    changed = true;
+                }
+            }
+        }
+        changed
+    }
+
+    pub fn check(&self, part: Part, answer: &Answer) -> Check {
+        match self.get(part) {
+            Some(expected) if answer.matches(expected) => Check::Correct,
+            Some(expected) => Check::Wrong {
+                expected: expected.clone(),
+            },
+            None => Check::Unknown,
+        }
+    }
+
+    fn parse(s: &str) -> Result<Self, Error> {
+        let mut answers = Self::default();
+
+        for line in s.lines().filter(|line| !line.trim().is_empty()) {
+            let (part, answer) = line
+                .strip_prefix("part ")
+                .and_then(|rest| rest.split_once(": "))
+                .ok_or_else(|| Error::Parser(format!("malformed line: {line}")))?;
+
+            match part.parse::<Part>() {
+                Ok(part) => answers.set(part, answer.replace("\\n", "\n").into()),
+                Err(_) => return Err(Error::Parser(format!("unknown part: {part}"))),
+            }
+        }
+
+        Ok(answers)
+    }
+
+    fn serialize(&self) -> String {
+        Part::ALL
+            .iter()
+            .filter_map(|&part| {
+                let answer = self.get(part)?.as_str().replace('\n', "\\n");
+                Some(format!("part {part}: {answer}\n"))
+            })
+            .collect()
+    }
+}
+
+fn get_path(year: Year, day: Day) -> String {
+    paths::data_file(year, "answers", day, "txt")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answers, Check};
+    use crate::Part;
+
+    #[test]
+    fn round_trips_answers() {
+        let answers = Answers {
+            part_1: Some("54597".into()),
+            part_2: Some("#..#\n####".into()),
+        };
+        let serialized = answers.serialize();
+        assert_eq!(serialized, "part 1: 54597\npart 2: #..#\\n####\n");
+        assert_eq!(Answers::parse(&serialized).unwrap(), answers);
+        assert!(Answers::parse("part 3: 1").is_err());
+    }
+
+    #[test]
+    fn checks_answers() {
+        let answers = Answers::parse("part 1: 42\n").unwrap();
+        assert_eq!(answers.check(Part::One, &"42".into()), Check::Correct);
+        assert_eq!(
+            answers.check(Part::One, &"43".into()),
+            Check::Wrong {
+                expected: "42".into()
+            }
+        );
+        assert_eq!(answers.check(Part::Two, &"42".into()), Check::Unknown);
+    }
+}
@@ -0,0 +1,95 @@
+/// Common interface over the ways the template can talk to adventofcode.com.
+/// Use `AOC_CLIENT=aoc-cli` to keep using the external aoc-cli binary instead of the built-in client.
+use std::{env, fmt::Display, io};
+
+use crate::template::{aoc_cli, aoc_http::HttpClient};
+use crate::{Answer, Day, Part, Year};
+
+#[derive(Debug)]
+pub enum AocClientError {
+    Cli(aoc_cli::AocCommandError),
+    SessionNotFound,
+    Http(String),
+    IoError(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::Cli(e) => write!(f, "{e}"),
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set ADVENT_OF_CODE_SESSION or create \"~/.adventofcode.session\"."
+            ),
+            AocClientError::Http(e) => write!(f, "request to advent of code failed: {e}"),
+            AocClientError::IoError(e) => write!(f, "could not write output files to file system: {e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IoError(e)
+    }
+}
+
+impl From<aoc_cli::AocCommandError> for AocClientError {
+    fn from(e: aoc_cli::AocCommandError) -> Self {
+        AocClientError::Cli(e)
+    }
+}
+
+pub trait AocClient {
+    /// Downloads the input and puzzle description for a day to the `inputs` and `puzzles` directories of its year.
+    fn download(&self, year: Year, day: Day) -> Result<(), AocClientError>;
+
+    /// Prints the puzzle description for a day to the terminal.
+    fn read(&self, year: Year, day: Day) -> Result<(), AocClientError>;
+
+    /// Submits an answer and returns the response message of the website.
+    fn submit(
+        &self,
+        year: Year,
+        day: Day,
+        part: Part,
+        answer: &Answer,
+    ) -> Result<String, AocClientError>;
+}
+
+/// Backend that shells out to the `aoc` binary.
+pub struct AocCli;
+
+impl AocClient for AocCli {
+    fn download(&self, year: Year, day: Day) -> Result<(), AocClientError> {
+        aoc_cli::download(year, day)?;
+        Ok(())
+    }
+
+    fn read(&self, year: Year, day: Day) -> Result<(), AocClientError> {
+        aoc_cli::read(year, day)?;
+        Ok(())
+    }
+
+    fn submit(
+        &self,
+        year: Year,
+        day: Day,
+        part: Part,
+        answer: &Answer,
+    ) -> Result<String, AocClientError> {
+        let output = aoc_cli::submit(year, day, part, answer)?;
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+}
+
+/// Returns the client selected by the `AOC_CLIENT` environment variable.
+/// Defaults to the built-in http client.
+pub fn client() -> Result<Box<dyn AocClient>, AocClientError> {
+    match env::var("AOC_CLIENT").as_deref() {
+        Ok("aoc-cli") => {
+            aoc_cli::check()?;
+            Ok(Box::new(AocCli))
+        }
+        _ => Ok(Box::new(HttpClient::from_env()?)),
+    }
+}
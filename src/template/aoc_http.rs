@@ -0,0 +1,221 @@
+/// Built-in client for adventofcode.com that authenticates with the session cookie.
+/// The base url can be overridden with `AOC_BASE_URL`, e.g. to point the client at a local stub server.
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use crate::template::aoc_client::{AocClient, AocClientError};
+use crate::template::{markdown, paths};
+use crate::{Answer, Day, Part, Year};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/fspoettel/advent-of-code-rust)"
+);
+
+pub struct HttpClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl HttpClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: base_url.trim_end_matches('/').into(),
+            session: session.trim().into(),
+        }
+    }
+
+    /// Creates a client from the session cookie and `AOC_BASE_URL`.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let session = read_session().ok_or(AocClientError::SessionNotFound)?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(Self::new(&base_url, &session))
+    }
+
+    /// Fetches the html of the puzzle page for a day.
+    pub fn fetch_puzzle(&self, year: Year, day: Day) -> Result<String, AocClientError> {
+        self.get(&self.day_url(year, day))
+    }
+
+    /// Fetches the personal puzzle input for a day.
+    pub fn fetch_input(&self, year: Year, day: Day) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.day_url(year, day)))
+    }
+
+    fn day_url(&self, year: Year, day: Day) -> String {
+        format!("{}/{year}/day/{}", self.base_url, day.into_inner())
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        self.agent
+            .get(url)
+            .set("Cookie", &self.cookie())
+            .call()
+            .map_err(|e| AocClientError::Http(e.to_string()))?
+            .into_string()
+            .map_err(AocClientError::from)
+    }
+}
+
+impl AocClient for HttpClient {
+    fn download(&self, year: Year, day: Day) -> Result<(), AocClientError> {
+        let input_path = paths::input_path(year, day);
+        let puzzle_path = paths::puzzle_path(year, day);
+
+        let input = self.fetch_input(year, day)?;
+        let puzzle = markdown::from_html(markdown::main_section(&self.fetch_puzzle(year, day)?));
+
+        for path in [&input_path, &puzzle_path] {
+            if let Some(dir) = Path::new(path).parent() {
+                fs::create_dir_all(dir)?;
+            }
+        }
+
+        fs::write(&input_path, input)?;
+        fs::write(&puzzle_path, puzzle)?;
+
+        println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+        println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+        Ok(())
+    }
+
+    fn read(&self, year: Year, day: Day) -> Result<(), AocClientError> {
+        let html = self.fetch_puzzle(year, day)?;
+        let description = markdown::articles(&html)
+            .into_iter()
+            .map(markdown::from_html)
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        println!("{description}");
+        Ok(())
+    }
+
+    fn submit(
+        &self,
+        year: Year,
+        day: Day,
+        part: Part,
+        answer: &Answer,
+    ) -> Result<String, AocClientError> {
+        let html = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(year, day)))
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer.as_str())])
+            .map_err(|e| AocClientError::Http(e.to_string()))?
+            .into_string()?;
+
+        let message = markdown::articles(&html)
+            .into_iter()
+            .map(markdown::from_html)
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        println!("{message}");
+        Ok(message)
+    }
+}
+
+/// Reads the session cookie from `ADVENT_OF_CODE_SESSION` or `~/.adventofcode.session`, like aoc-cli does.
+fn read_session() -> Option<String> {
+    if let Ok(session) = env::var("ADVENT_OF_CODE_SESSION") {
+        return Some(session);
+    }
+
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    let path = PathBuf::from(home).join(".adventofcode.session");
+    fs::read_to_string(path)
+        .ok()
+        .filter(|session| !session.trim().is_empty())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::HttpClient;
+    use crate::template::aoc_client::AocClient;
+    use crate::{day, year, Part};
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    /// Serves a single request with the given body and sends the received request back over a channel.
+    fn stub_server(body: &'static str) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut request_body = vec![0; content_length];
+            reader.read_exact(&mut request_body).unwrap();
+            request.push_str(&String::from_utf8(request_body).unwrap());
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            tx.send(request).unwrap();
+        });
+
+        (url, rx)
+    }
+
+    #[test]
+    fn fetches_input_with_session_cookie() {
+        let (url, rx) = stub_server("1abc2\n");
+        let client = HttpClient::new(&url, "abc\n");
+
+        assert_eq!(client.fetch_input(year!(2023), day!(1)).unwrap(), "1abc2\n");
+
+        let request = rx.recv().unwrap();
+        assert!(request.starts_with("GET /2023/day/1/input HTTP/1.1"));
+        assert!(request.contains("Cookie: session=abc\r\n"));
+    }
+
+    #[test]
+    fn submits_answer() {
+        let (url, rx) =
+            stub_server("<main><article><p>That's the right answer!</p></article></main>");
+        let client = HttpClient::new(&url, "abc");
+
+        let message = client
+            .submit(year!(2023), day!(4), Part::Two, &"42".into())
+            .unwrap();
+        assert_eq!(message, "That's the right answer!");
+
+        let request = rx.recv().unwrap();
+        assert!(request.starts_with("POST /2023/day/4/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+}
@@ -0,0 +1,334 @@
+/// Benchmark configuration and the statistics computed from collected samples.
+use std::fmt::Display;
+use std::time::Duration;
+
+use crate::template::json::Json;
+
+/// Default time budget of a benchmark if none is passed via `--time-budget`.
+pub const DEFAULT_BUDGET: Duration = Duration::from_secs(1);
+
+/// Bounds for the sample count that is derived from the time budget.
+const MIN_SAMPLES: u32 = 10;
+const MAX_SAMPLES: u32 = 10000;
+
+/// Options of the `--time` mode of the runner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchOptions {
+    /// Approximate wall time spent on warmup and measurement per part.
+    pub budget: Duration,
+    /// Fixed number of samples, derived from the budget if not set.
+    pub samples: Option<u32>,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            budget: DEFAULT_BUDGET,
+            samples: None,
+        }
+    }
+}
+
+impl BenchOptions {
+    /// Reads the options from the command line of a solution binary. Returns [`None`] if `--time` is not set.
+    pub fn from_args(args: &[String]) -> Option<Self> {
+        if !args.iter().any(|x| x == "--time") {
+            return None;
+        }
+
+        let value_of = |name: &str| {
+            args.iter()
+                .position(|x| x == name)
+                .and_then(|i| args.get(i + 1))
+        };
+
+        Some(Self {
+            budget: value_of("--time-budget")
+                .and_then(|x| parse_duration(x))
+                .unwrap_or(DEFAULT_BUDGET),
+            samples: value_of("--samples").and_then(|x| x.parse().ok()),
+        })
+    }
+
+    /// Converts the options to the arguments understood by [`BenchOptions::from_args`].
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![
+            "--time".into(),
+            "--time-budget".into(),
+            // nanoseconds keep budgets below a millisecond, e.g. `500µs`.
+            format!("{}ns", self.budget.as_nanos()),
+        ];
+
+        if let Some(samples) = self.samples {
+            args.push("--samples".into());
+            args.push(samples.to_string());
+        }
+
+        args
+    }
+
+    /// Number of measured iterations for a function that took `base_time` on its first run. At least one.
+    pub fn sample_count(&self, base_time: Duration) -> u32 {
+        self.samples
+            .map(|samples| samples.max(1))
+            .unwrap_or_else(|| {
+                let estimate = base_time.as_nanos().max(10);
+                let fitting = self.budget.as_nanos() / estimate;
+                u32::try_from(fitting)
+                    .unwrap_or(u32::MAX)
+                    .clamp(MIN_SAMPLES, MAX_SAMPLES)
+            })
+    }
+
+    /// Number of warmup iterations: a tenth of the samples, capped at a tenth of the budget.
+    pub fn warmup_count(&self, base_time: Duration) -> u32 {
+        let estimate = base_time.as_nanos().max(10);
+        let fitting = u32::try_from(self.budget.as_nanos() / 10 / estimate).unwrap_or(u32::MAX);
+        (self.sample_count(base_time) / 10).min(fitting).max(1)
+    }
+}
+
+/// Parses durations like `500ms`, `2s` or `1.5s`. Values without unit are read as seconds.
+pub fn parse_duration(s: &str) -> Option<Duration> {
+    let s = s.trim();
+    let (value, unit) = match s.find(|c: char| c.is_alphabetic() || c == 'µ') {
+        Some(i) => s.split_at(i),
+        None => (s, "s"),
+    };
+
+    let value: f64 = value.parse().ok()?;
+    if !value.is_finite() || value < 0.0 {
+        return None;
+    }
+
+    let seconds = match unit {
+        "ns" => value / 1e9,
+        "us" | "µs" => value / 1e6,
+        "ms" => value / 1e3,
+        "s" => value,
+        "m" => value * 60.0,
+        _ => return None,
+    };
+
+    Duration::try_from_secs_f64(seconds).ok()
+}
+
+/// Summary statistics of a benchmark.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    /// Number of samples the statistics were computed from, excluding outliers.
+    pub samples: u32,
+    /// Number of samples that were rejected as outliers.
+    pub outliers: u32,
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub stddev: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+}
+
+impl Stats {
+    /// Statistics of a single, untimed execution.
+    pub fn single(duration: Duration) -> Self {
+        Self {
+            samples: 1,
+            outliers: 0,
+            mean: duration,
+            median: duration,
+            min: duration,
+            max: duration,
+            stddev: Duration::ZERO,
+            p95: duration,
+            p99: duration,
+        }
+    }
+
+    /// Computes statistics from raw samples after rejecting outliers outside of Tukey's fences (1.5 IQR).
+    ///
+    /// # Panics
+    /// Panics if `samples` is empty.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(
+            !samples.is_empty(),
+            "cannot compute statistics without samples"
+        );
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let kept = reject_outliers(&sorted);
+        let count = kept.len() as f64;
+
+        let mean = kept.iter().map(Duration::as_secs_f64).sum::<f64>() / count;
+        let variance = kept
+            .iter()
+            .map(|x| (x.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / count;
+
+        Self {
+            samples: u32::try_from(kept.len()).unwrap_or(u32::MAX),
+            outliers: u32::try_from(sorted.len() - kept.len()).unwrap_or(u32::MAX),
+            mean: Duration::from_secs_f64(mean),
+            median: percentile(kept, 50.0),
+            min: kept[0],
+            max: kept[kept.len() - 1],
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            p95: percentile(kept, 95.0),
+            p99: percentile(kept, 99.0),
+        }
+    }
+
+    /// Value used for totals and tables.
+    pub fn headline(&self) -> Duration {
+        self.median
+    }
+
+    /// Serializes the statistics with durations in nanoseconds.
+    pub fn to_json(&self) -> Json {
+        let nanos = |d: Duration| Json::from(u64::try_from(d.as_nanos()).unwrap_or(u64::MAX));
+        Json::object([
+            ("samples", u64::from(self.samples).into()),
+            ("outliers", u64::from(self.outliers).into()),
+            ("mean_ns", nanos(self.mean)),
+            ("median_ns", nanos(self.median)),
+            ("min_ns", nanos(self.min)),
+            ("max_ns", nanos(self.max)),
+            ("stddev_ns", nanos(self.stddev)),
+            ("p95_ns", nanos(self.p95)),
+            ("p99_ns", nanos(self.p99)),
+        ])
+    }
+
+    pub fn from_json(value: &Json) -> Option<Self> {
+        let count = |key: &str| value.get(key)?.as_u64()?.try_into().ok();
+        let nanos = |key: &str| value.get(key)?.as_u64().map(Duration::from_nanos);
+        Some(Self {
+            samples: count("samples")?,
+            outliers: count("outliers")?,
+            mean: nanos("mean_ns")?,
+            median: nanos("median_ns")?,
+            min: nanos("min_ns")?,
+            max: nanos("max_ns")?,
+            stddev: nanos("stddev_ns")?,
+            p95: nanos("p95_ns")?,
+            p99: nanos("p99_ns")?,
+        })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "median {:.1?} · mean {:.1?} ± {:.1?} · min {:.1?} · max {:.1?} · p95 {:.1?} · p99 {:.1?}",
+            self.median, self.mean, self.stddev, self.min, self.max, self.p95, self.p99
+        )?;
+        if self.outliers > 0 {
+            write!(f, " · {} outliers rejected", self.outliers)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the slice of sorted samples within Tukey's fences.
+fn reject_outliers(sorted: &[Duration]) -> &[Duration] {
+    if sorted.len() < 4 {
+        return sorted;
+    }
+
+    let q1 = percentile(sorted, 25.0).as_secs_f64();
+    let q3 = percentile(sorted, 75.0).as_secs_f64();
+    let iqr = q3 - q1;
+    let (low, high) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+
+    let start = sorted.partition_point(|x| x.as_secs_f64() < low);
+    let end = sorted.partition_point(|x| x.as_secs_f64() <= high);
+    &sorted[start..end]
+}
+
+/// Nearest-rank percentile of sorted samples.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    let rank = (p / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_duration, BenchOptions, Stats};
+    use std::time::Duration;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|x| Duration::from_nanos(*x)).collect()
+    }
+
+    #[test]
+    fn computes_statistics() {
+        let stats = Stats::from_samples(&nanos(&[10, 20, 30, 40, 50]));
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.outliers, 0);
+        assert_eq!(stats.median, Duration::from_nanos(30));
+        assert_eq!(stats.mean, Duration::from_nanos(30));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.max, Duration::from_nanos(50));
+        assert_eq!(stats.p95, Duration::from_nanos(50));
+        assert_eq!(stats.stddev.as_nanos(), 14);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = Stats::from_samples(&nanos(&[10, 11, 12, 10, 11, 12, 10, 11, 5000]));
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.max, Duration::from_nanos(12));
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("500ms"), Some(Duration::from_millis(500)));
+        assert_eq!(parse_duration("1.5s"), Some(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("2"), Some(Duration::from_secs(2)));
+        assert_eq!(parse_duration("10µs"), Some(Duration::from_micros(10)));
+        assert_eq!(parse_duration("abc"), None);
+        assert_eq!(parse_duration("1 day"), None);
+        assert_eq!(parse_duration("1e30"), None);
+    }
+
+    #[test]
+    fn round_trips_options() {
+        let options = BenchOptions {
+            budget: Duration::from_millis(2500),
+            samples: Some(50),
+        };
+        assert_eq!(BenchOptions::from_args(&options.to_args()), Some(options));
+        assert_eq!(BenchOptions::from_args(&["--release".into()]), None);
+
+        let short = BenchOptions {
+            budget: Duration::from_micros(500),
+            samples: None,
+        };
+        assert_eq!(BenchOptions::from_args(&short.to_args()), Some(short));
+    }
+
+    #[test]
+    fn derives_sample_count_from_budget() {
+        let options = BenchOptions::default();
+        assert_eq!(options.sample_count(Duration::from_millis(10)), 100);
+        assert_eq!(options.sample_count(Duration::from_nanos(1)), 10000);
+        assert_eq!(options.sample_count(Duration::from_secs(2)), 10);
+        assert_eq!(options.warmup_count(Duration::from_millis(10)), 10);
+
+        let no_samples = BenchOptions {
+            samples: Some(0),
+            ..BenchOptions::default()
+        };
+        assert_eq!(no_samples.sample_count(Duration::from_millis(10)), 1);
+    }
+}
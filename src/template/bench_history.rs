@@ -0,0 +1,404 @@
+/// Module that keeps the results of every timed run of `cargo all` in `data/benchmarks.jsonl`.
+/// Each line is the parse step or one part of one run, keyed by run id, year, day and step.
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    process::{self, Command},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::template::bench::Stats;
+use crate::template::json::{self, Json};
+use crate::template::paths;
+use crate::template::readme_benchmarks::Timings;
+use crate::{Day, Part, Year};
+
+static HISTORY_PATH: &str = "data/benchmarks.jsonl";
+
+/// Default slowdown in percent above which a part counts as regressed.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse benchmark history: {e}"),
+            Error::IO(e) => write!(f, "could not access benchmark history: {e}"),
+        }
+    }
+}
+
+/// What an entry timed: the parse step of a solution that parses its input once, or a part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Step::Parse => write!(f, "Parse"),
+            Step::Part(part) => write!(f, "Part {part}"),
+        }
+    }
+}
+
+/// The timing of one step in one run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    /// Unique id of the run that recorded the entry.
+    pub run: String,
+    pub year: Year,
+    pub day: Day,
+    pub step: Step,
+    pub commit: String,
+    pub timestamp: u64,
+    pub stats: Stats,
+}
+
+impl Entry {
+    pub fn to_json(&self) -> Json {
+        let step = match self.step {
+            Step::Parse => ("phase", "parse".into()),
+            Step::Part(part) => ("part", part.into_inner().into()),
+        };
+        Json::object([
+            ("run", self.run.as_str().into()),
+            ("year", u64::from(self.year.into_inner()).into()),
+            ("day", self.day.into_inner().into()),
+            step,
+            ("commit", self.commit.as_str().into()),
+            ("timestamp", self.timestamp.into()),
+            ("duration", self.stats.to_json()),
+        ])
+    }
+
+    pub fn from_json(value: &Json) -> Option<Self> {
+        let day = value.get("day")?.as_u64()?;
+        // entries recorded before years were tracked belong to the default year.
+        let year = match value.get("year") {
+            Some(year) => Year::new(year.as_u64()?.try_into().ok()?)?,
+            None => paths::default_year()?,
+        };
+        let step = match value.get("phase").and_then(Json::as_str) {
+            Some("parse") => Step::Parse,
+            Some(_) => return None,
+            None => Step::Part(Part::new(value.get("part")?.as_u64()?.try_into().ok()?)?),
+        };
+        let commit: String = value.get("commit")?.as_str()?.into();
+        let timestamp = value.get("timestamp")?.as_u64()?;
+        // entries recorded before runs had an id are grouped by their commit and timestamp.
+        let run = match value.get("run") {
+            Some(run) => run.as_str()?.into(),
+            None => format!("{timestamp}-{commit}"),
+        };
+        Some(Self {
+            run,
+            year,
+            day: Day::new(day.try_into().ok()?)?,
+            step,
+            commit,
+            timestamp,
+            stats: Stats::from_json(value.get("duration")?)?,
+        })
+    }
+}
+
+/// Converts the timings of a run to history entries.
+fn entries_from_timings(
+    run: &str,
+    year: Year,
+    timings: &[Timings],
+    commit: &str,
+    timestamp: u64,
+) -> Vec<Entry> {
+    timings
+        .iter()
+        .flat_map(|t| {
+            let parse = t.parse.map(|stats| (Step::Parse, stats));
+            let parts = Part::ALL.map(|part| t.get(part).map(|stats| (Step::Part(part), *stats)));
+            parse
+                .into_iter()
+                .chain(parts.into_iter().flatten())
+                .map(move |x| (t.day, x))
+        })
+        .map(|(day, (step, stats))| Entry {
+            run: run.into(),
+            year,
+            day,
+            step,
+            commit: commit.into(),
+            timestamp,
+            stats,
+        })
+        .collect()
+}
+
+/// Entries that were recorded by the same run.
+#[derive(Debug)]
+pub struct Run<'a> {
+    pub id: &'a str,
+    pub year: Year,
+    pub commit: &'a str,
+    pub timestamp: u64,
+    pub entries: Vec<&'a Entry>,
+}
+
+#[derive(Debug, Default)]
+pub struct History {
+    pub entries: Vec<Entry>,
+}
+
+impl History {
+    /// Loads the history. Returns an empty history if no timed run was recorded yet.
+    pub fn load() -> Result<Self, Error> {
+        if !Path::new(HISTORY_PATH).exists() {
+            return Ok(Self::default());
+        }
+        Self::parse(&fs::read_to_string(HISTORY_PATH)?)
+    }
+
+    /// Appends the timings of a run of `cargo all --time` for the current commit.
+    pub fn record(year: Year, timings: &[Timings]) -> Result<(), Error> {
+        let now = now();
+        Self::append(&entries_from_timings(
+            &run_id(now),
+            year,
+            timings,
+            &current_commit(),
+            now.as_secs(),
+        ))
+    }
+
+    /// Appends the entries of a run to the history file.
+    pub fn append(entries: &[Entry]) -> Result<(), Error> {
+        fs::create_dir_all("data")?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(HISTORY_PATH)?;
+        for entry in entries {
+            writeln!(file, "{}", entry.to_json())?;
+        }
+        Ok(())
+    }
+
+    fn parse(s: &str) -> Result<Self, Error> {
+        let entries = s
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let value = json::parse(line).map_err(|e| Error::Parser(e.to_string()))?;
+                Entry::from_json(&value)
+                    .ok_or_else(|| Error::Parser(format!("malformed entry: {line}")))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { entries })
+    }
+
+    /// Groups the entries into runs, in the order they were recorded.
+    pub fn runs(&self) -> Vec<Run<'_>> {
+        let mut runs: Vec<Run> = vec![];
+
+        for entry in &self.entries {
+            match runs
+                .iter_mut()
+                .find(|run| run.id == entry.run && run.year == entry.year)
+            {
+                Some(run) => run.entries.push(entry),
+                None => runs.push(Run {
+                    id: &entry.run,
+                    year: entry.year,
+                    commit: &entry.commit,
+                    timestamp: entry.timestamp,
+                    entries: vec![entry],
+                }),
+            }
+        }
+
+        runs
+    }
+}
+
+/// The timing of a step in a baseline run and in the latest run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Change {
+    pub day: Day,
+    pub step: Step,
+    pub baseline: Duration,
+    pub latest: Duration,
+}
+
+impl Change {
+    /// Relative change of the latest timing in percent, positive if the step got slower.
+    pub fn percent(&self) -> f64 {
+        let baseline = self.baseline.as_secs_f64();
+        if baseline == 0.0 {
+            return 0.0;
+        }
+        (self.latest.as_secs_f64() - baseline) / baseline * 100.0
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.percent() > threshold
+    }
+}
+
+/// Compares the steps that were timed in both runs.
+pub fn compare(baseline: &Run, latest: &Run) -> Vec<Change> {
+    latest
+        .entries
+        .iter()
+        .filter_map(|entry| {
+            let previous = baseline
+                .entries
+                .iter()
+                .find(|e| e.day == entry.day && e.step == entry.step)?;
+            Some(Change {
+                day: entry.day,
+                step: entry.step,
+                baseline: previous.stats.headline(),
+                latest: entry.stats.headline(),
+            })
+        })
+        .collect()
+}
+
+/// Short hash of the checked out commit, suffixed with `-dirty` if the working tree has changes.
+fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash) if git(&["status", "--porcelain"]).is_some_and(|s| !s.is_empty()) => {
+            format!("{hash}-dirty")
+        }
+        Some(hash) => hash,
+        None => "unknown".into(),
+    }
+}
+
+fn now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+/// Id of a run, unique even for runs that start within the same second on the same commit.
+fn run_id(now: Duration) -> String {
+    format!("{:x}-{:x}", now.as_nanos(), process::id())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compare, entries_from_timings, History, Step};
+    use crate::template::bench::Stats;
+    use crate::template::readme_benchmarks::Timings;
+    use crate::{day, year};
+    use std::time::Duration;
+
+    fn timings(part_1: u64, part_2: Option<u64>) -> Vec<Timings> {
+        vec![Timings {
+            day: day!(1),
+            parse: Some(Stats::single(Duration::from_micros(5))),
+            part_1: Some(Stats::single(Duration::from_micros(part_1))),
+            part_2: part_2.map(|x| Stats::single(Duration::from_micros(x))),
+            combined: false,
+            total_nanos: 0_f64,
+        }]
+    }
+
+    fn history() -> History {
+        let year = year!(2023);
+        let mut entries = entries_from_timings("a", year, &timings(100, None), "abc1234", 10);
+        entries.extend(entries_from_timings(
+            "b",
+            year,
+            &timings(100, Some(50)),
+            "def5678",
+            20,
+        ));
+        // a second run within the same second on the same commit.
+        entries.extend(entries_from_timings(
+            "c",
+            year,
+            &timings(150, Some(52)),
+            "def5678",
+            20,
+        ));
+        History { entries }
+    }
+
+    #[test]
+    fn round_trips_entries() {
+        let history = history();
+        let serialized: String = history
+            .entries
+            .iter()
+            .map(|e| format!("{}\n", e.to_json()))
+            .collect();
+        assert_eq!(
+            History::parse(&serialized).unwrap().entries,
+            history.entries
+        );
+    }
+
+    #[test]
+    fn groups_runs() {
+        let history = history();
+        let runs = history.runs();
+        assert_eq!(runs.len(), 3);
+        assert_eq!(runs[0].commit, "abc1234");
+        assert_eq!(runs[0].entries.len(), 2);
+        assert_eq!(runs[2].entries.len(), 3);
+        assert_eq!(runs[2].entries[0].step, Step::Parse);
+    }
+
+    #[test]
+    fn groups_runs_without_id_by_commit_and_timestamp() {
+        let history = History::parse(concat!(
+            r#"{"year":2023,"day":1,"part":1,"commit":"abc1234","timestamp":10,"duration":{"samples":1,"outliers":0,"mean_ns":1,"median_ns":1,"min_ns":1,"max_ns":1,"stddev_ns":0,"p95_ns":1,"p99_ns":1}}"#,
+            "\n",
+            r#"{"year":2023,"day":1,"part":2,"commit":"abc1234","timestamp":10,"duration":{"samples":1,"outliers":0,"mean_ns":1,"median_ns":1,"min_ns":1,"max_ns":1,"stddev_ns":0,"p95_ns":1,"p99_ns":1}}"#,
+        ))
+        .unwrap();
+        let runs = history.runs();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].entries.len(), 2);
+    }
+
+    #[test]
+    fn detects_regressions() {
+        let history = history();
+        let runs = history.runs();
+        let changes = compare(&runs[1], &runs[2]);
+        assert_eq!(changes.len(), 3);
+        assert_eq!(changes[0].step, Step::Parse);
+        assert_eq!(changes[1].percent().round(), 50.0);
+        assert!(changes[1].is_regression(10.0));
+        assert!(!changes[2].is_regression(10.0));
+
+        // parts missing from the baseline are not compared.
+        assert_eq!(compare(&runs[0], &runs[2]).len(), 2);
+    }
+}
@@ -1,39 +1,152 @@
-use std::io;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::process::{self, Command};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::{env, thread};
 
 use crate::template::{
+    bench::{BenchOptions, Stats},
+    bench_history::History,
+    paths,
     readme_benchmarks::{self, Timings},
+    registry::Solutions,
+    report::{DayReport, Format, Phase, Status},
+    runner,
+    timeout::Timeouts,
     ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
-use crate::{all_days, Day};
+use crate::{all_days, Day, Part, Year};
+
+/// Builds and runs the `all` binary, which includes every solution in `src/bin`, for the solutions of a year.
+pub fn handle(
+    year: Year,
+    is_release: bool,
+    bench_options: Option<BenchOptions>,
+    format: Format,
+    jobs: Option<usize>,
+    timeouts: Timeouts,
+) {
+    let mut args: Vec<String> = vec!["run".into(), "--quiet".into(), "--bin".into(), "all".into()];
+
+    if is_release {
+        args.push("--release".into());
+    }
 
-pub fn handle(is_release: bool, is_timed: bool) {
-    let mut timings: Vec<Timings> = vec![];
+    args.push("--".into());
+    args.push("--year".into());
+    args.push(year.to_string());
+    args.push("--format".into());
+    args.push(format.to_string());
 
-    all_days().for_each(|day| {
-        if day > 1 {
-            println!();
+    if let Some(jobs) = jobs {
+        args.push("--jobs".into());
+        args.push(jobs.to_string());
+    }
+
+    if let Some(bench_options) = bench_options {
+        // mirror `--time` flags to the binary.
+        args.extend(bench_options.to_args());
+    }
+
+    args.extend(timeouts.to_args());
+
+    let status = Command::new("cargo").args(&args).status();
+
+    match status {
+        Ok(status) if status.success() => {}
+        Ok(status) => process::exit(status.code().unwrap_or(1)),
+        Err(e) => {
+            eprintln!("failed to run solutions: {e}");
+            process::exit(1);
         }
+    }
+}
 
-        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-        println!("------");
+/// Runs every registered solution of the year passed with `--year`, or the default year. Entry point of the `all` binary.
+///
+/// Days run one after another by default. With `--jobs <n>` greater than one, days run concurrently in child
+/// processes of the binary and their output is printed in day order.
+/// With `--timeout` or `--part-timeout`, days always run in child processes, so that a day that runs past
+/// its timeout can be stopped without stopping the others.
+pub fn run(solutions: &Solutions) {
+    let args: Vec<String> = env::args().collect();
+    let bench_options = BenchOptions::from_args(&args);
+    let format = Format::from_args(&args);
+    let is_release = !cfg!(debug_assertions);
+
+    let value_of = |name: &str| {
+        args.iter()
+            .position(|x| x == name)
+            .and_then(|i| args.get(i + 1))
+    };
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    let year = match value_of("--year") {
+        Some(year) => year.parse::<Year>().ok(),
+        None => paths::default_year(),
+    };
+
+    let Some(year) = year else {
+        eprintln!("no valid year given. Pass `--year` or set AOC_YEAR in \".cargo/config.toml\".");
+        process::exit(1);
+    };
 
-        if output.is_empty() {
-            println!("Not solved.");
-        } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
+    // a single day, spawned by a parallel run or by a run with timeouts.
+    if let Some(day) = value_of("--day").and_then(|x| x.parse::<Day>().ok()) {
+        if let Some(solution) = solutions.get(year, day) {
+            runner::run_source(solution);
         }
-    });
+        return;
+    }
+
+    let jobs = value_of("--jobs").and_then(|x| x.parse().ok()).unwrap_or(1);
+
+    let reports = if jobs > 1 || Timeouts::from_args(&args).is_set() {
+        run_parallel(solutions, year, jobs, format)
+    } else {
+        run_sequential(solutions, year, format)
+    };
+
+    let timings: Vec<Timings> = reports.iter().map(get_timings).collect();
+    let has_failures = reports.iter().any(DayReport::is_failure);
+
+    // `--no-readme` is passed by commands that only consume the records, like `verify`.
+    if !args.iter().any(|x| x == "--no-readme") {
+        update_readme(year, timings, bench_options.as_ref(), format, is_release);
+    }
+
+    if has_failures {
+        process::exit(1);
+    }
+}
 
-    if is_timed {
+/// Refreshes the benchmarks in the readme after a timed run.
+fn update_readme(
+    year: Year,
+    timings: Vec<Timings>,
+    bench_options: Option<&BenchOptions>,
+    format: Format,
+    is_release: bool,
+) {
+    if bench_options.is_some() {
         let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
 
-        println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
+        if format == Format::Text {
+            println!(
+                "\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
 
         if is_release {
-            match readme_benchmarks::update(timings, total_millis) {
+            if let Err(e) = History::record(year, &timings) {
+                eprintln!("Failed to record benchmarks: {e}");
+            }
+
+            match readme_benchmarks::update(year, timings, total_millis) {
+                // keep stdout free of anything but records in json mode.
+                Ok(()) if format == Format::Json => {
+                    eprintln!("Successfully updated README with benchmarks.");
+                }
                 Ok(()) => println!("Successfully updated README with benchmarks."),
                 Err(_) => {
                     eprintln!("Failed to update readme with benchmarks.");
@@ -43,212 +156,381 @@ pub fn handle(is_release: bool, is_timed: bool) {
     }
 }
 
-#[derive(Debug)]
-pub enum Error {
-    BrokenPipe,
-    Parser(String),
-    IO(io::Error),
+/// Runs the days one after another in this process and returns the records of each day.
+fn run_sequential(solutions: &Solutions, year: Year, format: Format) -> Vec<DayReport> {
+    let mut results = vec![];
+
+    all_days(year).for_each(|day| {
+        print_header(day, format);
+
+        let Some(solution) = solutions.get(year, day) else {
+            print_not_solved(format);
+            return;
+        };
+
+        if !has_input(year, day) {
+            print_missing_input(year, day);
+            return;
+        }
+
+        results.extend(runner::run_solution(solution));
+    });
+
+    results
+}
+
+/// Runs days on `jobs` worker threads, each spawning the binary for one day.
+/// Output is buffered per day and printed as soon as all previous days are done.
+fn run_parallel(solutions: &Solutions, year: Year, jobs: usize, format: Format) -> Vec<DayReport> {
+    let days: Vec<Day> = all_days(year).collect();
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    let mut results = vec![];
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let (days, next, sender) = (&days, &next, sender.clone());
+            scope.spawn(move || {
+                while let Some(&day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let output = (solutions.get(year, day).is_some() && has_input(year, day))
+                        .then(|| child_commands::run_day(year, day));
+                    if sender.send((day, output)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut remaining = days.iter().copied().peekable();
+
+        for (day, output) in receiver {
+            pending.insert(day, output);
+
+            while let Some(output) = remaining.peek().and_then(|day| pending.remove(day)) {
+                let day = remaining.next().unwrap();
+                print_header(day, format);
+
+                match output {
+                    Some(Ok(output)) => {
+                        results.push(child_commands::print_output(day, &output, format));
+                    }
+                    Some(Err(e)) => eprintln!("failed to run day {day}: {e}"),
+                    None if solutions.get(year, day).is_none() => print_not_solved(format),
+                    None => print_missing_input(year, day),
+                }
+            }
+        }
+    });
+
+    results
 }
 
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
+fn print_header(day: Day, format: Format) {
+    if format == Format::Text {
+        if day > 1 {
+            println!();
+        }
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+fn print_not_solved(format: Format) {
+    if format == Format::Text {
+        println!("Not solved.");
+    }
 }
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::Day;
-    use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
-    };
+fn has_input(year: Year, day: Day) -> bool {
+    Path::new(&paths::input_path(year, day)).exists()
+}
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
-        }
+fn print_missing_input(year: Year, day: Day) {
+    eprintln!(
+        "Input file \"{}\" is missing. Run `{}`.",
+        paths::input_path(year, day),
+        paths::cargo_command("download", year, day)
+    );
+}
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+mod child_commands {
+    use std::io::{self, Read};
+    use std::process::{Command, Output, Stdio};
+    use std::time::{Duration, Instant};
+    use std::{env, thread};
+
+    use crate::template::bench::{BenchOptions, Stats};
+    use crate::template::report::{DayReport, Format, PartReport, PhaseReport, Status};
+    use crate::template::timeout::{Timeouts, KILL_GRACE};
+    use crate::{Day, Part, Year};
+
+    /// The captured output of a day.
+    pub struct DayOutput {
+        pub output: Output,
+        /// Set if the child ran past the day timeout and was killed.
+        pub killed_after: Option<Duration>,
+    }
 
-        if is_release {
-            args.push("--release");
+    /// Runs one day in a child process of the current binary and captures its output.
+    /// The child reports its own timeouts. If it still runs shortly after the longest time the day
+    /// can take, see [`Timeouts::day_limit`], it is killed.
+    pub fn run_day(year: Year, day: Day) -> Result<DayOutput, io::Error> {
+        let args: Vec<String> = env::args().collect();
+
+        let mut cmd_args = vec![
+            "--year".into(),
+            year.to_string(),
+            "--day".into(),
+            day.to_string(),
+            "--format".into(),
+            "json".into(),
+        ];
+
+        if let Some(bench_options) = BenchOptions::from_args(&args) {
+            cmd_args.extend(bench_options.to_args());
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
-        }
+        let timeouts = Timeouts::from_args(&args);
+        cmd_args.extend(timeouts.to_args());
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        let limit = timeouts.day_limit().map(|limit| limit + KILL_GRACE);
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
+        let mut child = Command::new(env::current_exe()?)
+            .args(&cmd_args)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()?;
+        let stdout = read_in_background(child.stdout.take());
+        let stderr = read_in_background(child.stderr.take());
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
-
-        let mut output = vec![];
+        let started = Instant::now();
+        let mut killed_after = None;
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+        let status = loop {
+            if let Some(status) = child.try_wait()? {
+                break status;
+            }
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
-        }
+            if limit.is_some_and(|limit| started.elapsed() >= limit) {
+                // the child might have exited in the meantime, which `wait` picks up.
+                let _ = child.kill();
+                killed_after = Some(started.elapsed());
+                break child.wait()?;
+            }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+            thread::sleep(Duration::from_millis(10));
+        };
 
-        Ok(output)
+        Ok(DayOutput {
+            output: Output {
+                status,
+                stdout: stdout.join().unwrap_or_default(),
+                stderr: stderr.join().unwrap_or_default(),
+            },
+            killed_after,
+        })
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
-        let mut timings = super::Timings {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+    fn read_in_background(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<Vec<u8>> {
+        thread::spawn(move || {
+            let mut buf = vec![];
+            if let Some(mut pipe) = pipe {
+                let _ = pipe.read_to_end(&mut buf);
+            }
+            buf
+        })
+    }
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+    /// Prints the buffered output of a day and returns its records.
+    /// Parts of a killed day that did not report are reported as timed out.
+    pub fn print_output(day: Day, day_output: &DayOutput, format: Format) -> DayReport {
+        let output = &day_output.output;
+        let mut report = DayReport::new(day);
+
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            let text = if let Some(part) = PartReport::parse_line(line) {
+                let text = part.to_text();
+                report.parts.push(part);
+                text
+            } else if let Some(phase) = PhaseReport::parse_line(line) {
+                let text = phase.to_text();
+                report.phase = Some(phase);
+                text
+            } else {
+                println!("{line}");
+                continue;
+            };
+
+            if format == Format::Text {
+                println!("{text}");
+            } else {
+                println!("{line}");
+            }
+        }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
+        eprint!("{}", String::from_utf8_lossy(&output.stderr));
+
+        if let Some(elapsed) = day_output.killed_after {
+            eprintln!("Day {day} did not stop at its timeout and was killed after {elapsed:.1?}.");
+
+            let missing: Vec<Part> = Part::ALL
+                .into_iter()
+                .filter(|&part| report.parts.iter().all(|p| p.part != part))
+                .collect();
+
+            for part in missing {
+                let timed_out = PartReport {
+                    day,
+                    part,
+                    input: None,
+                    status: Status::TimedOut,
+                    answer: None,
+                    expected: None,
+                    error: None,
+                    stats: Some(Stats::single(elapsed)),
                 };
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+                match format {
+                    Format::Text => println!("{}", timed_out.to_text()),
+                    Format::Json => println!("{}", timed_out.to_json()),
                 }
+                report.parts.push(timed_out);
+            }
+        } else if !output.status.success() {
+            eprintln!("Day {day} exited with {}.", output.status);
+        }
 
-                timings.total_nanos += nanos;
-            });
+        report
+    }
+}
+
+/// Collects the timings of the shared phase and the solved parts of a day.
+/// For days that solve both parts at once, the time of `solve` counts as part one.
+fn get_timings(report: &DayReport) -> Timings {
+    let phase = report.phase.as_ref().filter(|phase| !phase.is_failure());
+    let mut timings = Timings {
+        day: report.day,
+        parse: phase
+            .filter(|phase| phase.phase == Phase::Parse)
+            .map(|phase| phase.stats),
+        part_1: None,
+        part_2: None,
+        combined: phase.is_some_and(|phase| phase.phase == Phase::Solve),
+        total_nanos: 0_f64,
+    };
 
-        timings
+    if timings.combined {
+        timings.part_1 = phase.map(|phase| phase.stats);
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    for part in report.parts.iter().filter(|r| r.status == Status::Solved) {
+        if let Some(stats) = part.stats {
+            timings.set(part.part, stats);
+        }
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
+    #[allow(clippy::cast_precision_loss)]
+    let nanos = |stats: &Stats| stats.headline().as_nanos() as f64;
+    timings.total_nanos = timings.parse.iter().map(nanos).sum::<f64>()
+        + Part::ALL
+            .iter()
+            .filter_map(|&part| timings.get(part))
+            .map(nanos)
+            .sum::<f64>();
 
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
+    timings
+}
 
-        Some((str_timing, parsed_timing))
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::get_timings;
+    use crate::template::bench::Stats;
+    use crate::template::report::{DayReport, PartReport, Phase, PhaseReport, Status};
+    use crate::{day, Part};
+    use std::time::Duration;
+
+    fn report(part: Part, status: Status, nanos: u64) -> PartReport {
+        PartReport {
+            day: day!(1),
+            part,
+            input: None,
+            status,
+            answer: (status == Status::Solved).then(|| "42".into()),
+            expected: None,
+            error: None,
+            stats: Some(Stats::single(Duration::from_nanos(nanos))),
+        }
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    fn day_report(parts: Vec<PartReport>) -> DayReport {
+        DayReport {
+            parts,
+            ..DayReport::new(day!(1))
+        }
     }
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
+    #[test]
+    fn collects_timings_of_solved_parts() {
+        let res = get_timings(&day_report(vec![
+            report(Part::One, Status::Solved, 74),
+            report(Part::Two, Status::Solved, 74_130_000),
+        ]));
+        assert_eq!(res.total_nanos, 74_130_074_f64);
+        assert_eq!(res.part_1.unwrap().median, Duration::from_nanos(74));
+        assert_eq!(res.part_2.unwrap().median, Duration::from_nanos(74_130_000));
+    }
 
-        #[test]
-        fn test_well_formed() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
-        }
+    #[test]
+    fn skips_unsolved_parts() {
+        let res = get_timings(&day_report(vec![
+            report(Part::One, Status::Unsolved, 100),
+            report(Part::Two, Status::Unsolved, 100),
+        ]));
+        assert_eq!(res.total_nanos, 0_f64);
+        assert_eq!(res.part_1.is_none(), true);
+        assert_eq!(res.part_2.is_none(), true);
+    }
 
-        #[test]
-        fn test_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
-        }
+    #[test]
+    fn adds_parse_time_to_total() {
+        let res = get_timings(&DayReport {
+            phase: Some(PhaseReport {
+                day: day!(1),
+                phase: Phase::Parse,
+                input: None,
+                error: None,
+                stats: Stats::single(Duration::from_nanos(1_000)),
+            }),
+            ..day_report(vec![report(Part::One, Status::Solved, 74)])
+        });
+        assert_eq!(res.total_nanos, 1_074_f64);
+        assert_eq!(res.parse.unwrap().median, Duration::from_nanos(1_000));
+        assert!(res.part_2.is_none());
+    }
 
-        #[test]
-        fn test_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
-        }
+    #[test]
+    fn counts_combined_solve_as_part_one() {
+        let solved_together = |part| PartReport {
+            stats: None,
+            ..report(part, Status::Solved, 0)
+        };
+        let res = get_timings(&DayReport {
+            phase: Some(PhaseReport {
+                day: day!(1),
+                phase: Phase::Solve,
+                input: None,
+                error: None,
+                stats: Stats::single(Duration::from_nanos(5_000)),
+            }),
+            ..day_report(vec![solved_together(Part::One), solved_together(Part::Two)])
+        });
+        assert!(res.combined);
+        assert_eq!(res.total_nanos, 5_000_f64);
+        assert_eq!(res.part_1.unwrap().median, Duration::from_nanos(5_000));
+        assert!(res.parse.is_none() && res.part_2.is_none());
     }
 }
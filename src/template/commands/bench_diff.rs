@@ -0,0 +1,84 @@
+use std::process;
+
+use crate::template::bench_history::{self, History};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::Year;
+
+/// Compares the latest timed run of a year against a baseline and exits with an error if a part or parse step regressed.
+/// The baseline is the previous run, or the latest earlier run of a commit that starts with `baseline`.
+pub fn handle(year: Year, baseline: Option<String>, threshold: f64) {
+    let history = match History::load() {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let runs: Vec<_> = history
+        .runs()
+        .into_iter()
+        .filter(|run| run.year == year)
+        .collect();
+
+    let Some((latest, previous)) = runs.split_last() else {
+        eprintln!("No benchmarks recorded for {year} yet. Run `cargo time` to record one.");
+        process::exit(1);
+    };
+
+    let baseline_run = match &baseline {
+        Some(commit) => previous
+            .iter()
+            .rev()
+            .find(|r| r.commit.starts_with(commit.as_str())),
+        None => previous.last(),
+    };
+
+    let Some(baseline_run) = baseline_run else {
+        match baseline {
+            Some(commit) => eprintln!("No benchmark run found for commit {commit}."),
+            None => {
+                eprintln!("Need at least two benchmark runs to compare. Run `cargo time` again.")
+            }
+        }
+        process::exit(1);
+    };
+
+    println!(
+        "Comparing {ANSI_BOLD}{}{ANSI_RESET} against baseline {ANSI_BOLD}{}{ANSI_RESET} (threshold: {threshold}%)",
+        latest.commit, baseline_run.commit
+    );
+    println!();
+
+    let changes = bench_history::compare(baseline_run, latest);
+    let mut regressions = 0;
+
+    for change in &changes {
+        let is_regression = change.is_regression(threshold);
+        if is_regression {
+            regressions += 1;
+        }
+
+        println!(
+            "Day {} {}: {:.1?} → {:.1?} ({ANSI_ITALIC}{:+.1}%{ANSI_RESET}){}",
+            change.day,
+            change.step,
+            change.baseline,
+            change.latest,
+            change.percent(),
+            if is_regression { " ✖ regression" } else { "" }
+        );
+    }
+
+    println!();
+
+    if regressions > 0 {
+        println!(
+            "{ANSI_BOLD}{regressions} of {} timings regressed.{ANSI_RESET}",
+            changes.len()
+        );
+        process::exit(1);
+    }
+
+    println!("No regressions in {} timings.", changes.len());
+}
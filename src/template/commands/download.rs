@@ -1,15 +1,28 @@
-use crate::template::aoc_cli;
-use crate::Day;
 use std::process;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+use crate::template::{aoc_client, paths, puzzle};
+use crate::{Day, Year};
+
+pub fn handle(year: Year, day: Day) {
+    let client = match aoc_client::client() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("failed to set up advent of code client: {e}");
+            process::exit(1);
+        }
+    };
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if let Err(e) = client.download(year, day) {
+        eprintln!("failed to download puzzle: {e}");
         process::exit(1);
     };
+
+    match puzzle::store_answers(year, day) {
+        Ok(0) => {}
+        Ok(count) => println!(
+            "🎄 Stored {count} confirmed answer(s) in \"{}\".",
+            paths::data_file(year, "answers", day, "txt")
+        ),
+        Err(e) => eprintln!("failed to store confirmed answers: {e}"),
+    }
 }
@@ -0,0 +1,95 @@
+use std::{fs, process};
+
+use crate::template::examples::{self, Example};
+use crate::template::paths;
+use crate::{Day, Year};
+
+pub fn handle(year: Year, day: Day) {
+    let puzzle_path = paths::puzzle_path(year, day);
+    let module_path = paths::bin_path(year, day);
+
+    let Ok(puzzle) = fs::read_to_string(&puzzle_path) else {
+        eprintln!(
+            "Puzzle description \"{puzzle_path}\" is missing. Run `{}` first.",
+            paths::cargo_command("download", year, day)
+        );
+        process::exit(1);
+    };
+
+    let examples = examples::extract(&puzzle);
+
+    let part_two = examples.part_two.as_ref().map(|example| Example {
+        // most puzzles reuse the example of part one.
+        input: example
+            .input
+            .clone()
+            .filter(|input| Some(input) != examples.part_one.input.as_ref()),
+        answer: example.answer.clone(),
+    });
+
+    let examples_dir = format!("{}/examples", paths::data_dir(year));
+    if let Err(e) = fs::create_dir_all(&examples_dir) {
+        eprintln!("Failed to create example directory: {e}");
+        process::exit(1);
+    }
+
+    write_example(&format!("{examples_dir}/{day}.txt"), &examples.part_one);
+    if let Some(part_two) = part_two.as_ref().filter(|example| example.input.is_some()) {
+        write_example(&format!("{examples_dir}/{day}-2.txt"), part_two);
+    }
+
+    let Ok(mut module) = fs::read_to_string(&module_path) else {
+        println!("Module file \"{module_path}\" is missing, skipped updating its tests.");
+        return;
+    };
+
+    let parts = [
+        ("test_part_one", Some(&examples.part_one)),
+        ("test_part_two", part_two.as_ref()),
+    ];
+
+    for (test_name, example) in parts {
+        let Some(example) = example else {
+            continue;
+        };
+
+        if let Some(answer) = &example.answer {
+            if let Some(updated) = examples::fill_test_answer(&module, test_name, answer) {
+                println!("Set expected answer of {test_name} to {answer}");
+                module = updated;
+            }
+        }
+
+        if test_name == "test_part_two" && example.input.is_some() {
+            if let Some(updated) = examples::use_part_example(&module, test_name, 2) {
+                module = updated;
+            }
+        }
+    }
+
+    if let Err(e) = fs::write(&module_path, module) {
+        eprintln!("Failed to update module file: {e}");
+        process::exit(1);
+    }
+}
+
+/// Writes an example input unless the file already has content, which might have been edited by hand.
+fn write_example(path: &str, example: &Example) {
+    let Some(input) = &example.input else {
+        println!("No example found for \"{path}\"");
+        return;
+    };
+
+    if fs::read_to_string(path).is_ok_and(|content| !content.trim().is_empty()) {
+        println!("Kept existing example file \"{path}\"");
+        return;
+    }
+
+    match fs::write(path, input) {
+        Ok(()) => println!("Created example file \"{path}\""),
+        Err(e) => {
+            eprintln!("Failed to write example file: {e}");
+            process::exit(1);
+        }
+    }
+}
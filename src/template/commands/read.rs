@@ -1,16 +1,19 @@
 use std::process;
 
-use crate::template::aoc_cli;
-use crate::Day;
+use crate::template::aoc_client;
+use crate::{Day, Year};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+pub fn handle(year: Year, day: Day) {
+    let client = match aoc_client::client() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("failed to set up advent of code client: {e}");
+            process::exit(1);
+        }
+    };
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if let Err(e) = client.read(year, day) {
+        eprintln!("failed to read puzzle: {e}");
         process::exit(1);
     };
 }
@@ -1,10 +1,12 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::Day;
+use crate::template::paths;
+use crate::{Day, Year};
 
 const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
 
@@ -38,14 +40,61 @@ fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
+/// Creates a file if it does not exist, keeping the content of an input that was downloaded before.
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// The module template of a day. Days of other years than the default year refer to their year.
+fn module_template(year: Year, day: Day) -> String {
+    let template = MODULE_TEMPLATE.replace("DAY_NUMBER", &day.into_inner().to_string());
+
+    match paths::scope(year) {
+        Some(year) => template
+            .replace("solution!(", &format!("solution!({year}, "))
+            .replace(
+                "read_file(\"examples\", DAY)",
+                "read_year_file(\"examples\", YEAR, DAY)",
+            ),
+        None => template,
+    }
+}
+
+/// Binaries in year directories are not discovered by cargo, so they are declared in `Cargo.toml`.
+fn register_bin(year: Year, day: Day) -> Result<bool, std::io::Error> {
+    let name = paths::bin_name(year, day);
+    let manifest = fs::read_to_string("Cargo.toml")?;
+
+    if manifest.contains(&format!("name = \"{name}\"")) {
+        return Ok(false);
+    }
+
+    let mut file = OpenOptions::new().append(true).open("Cargo.toml")?;
+    write!(
+        file,
+        "\n[[bin]]\nname = \"{name}\"\npath = \"{}\"\n",
+        paths::bin_path(year, day)
+    )?;
+    Ok(true)
+}
+
+pub fn handle(year: Year, day: Day) {
+    let input_path = paths::input_path(year, day);
+    let example_path = paths::data_file(year, "examples", day, "txt");
+    let module_path = paths::bin_path(year, day);
+
+    for path in [&input_path, &example_path, &module_path] {
+        if let Some(dir) = Path::new(path).parent() {
+            if let Err(e) = fs::create_dir_all(dir) {
+                eprintln!("Failed to create directory: {e}");
+                process::exit(1);
+            }
+        }
+    }
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -55,11 +104,7 @@ pub fn handle(day: Day) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("DAY_NUMBER", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match file.write_all(module_template(year, day).as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -89,6 +134,23 @@ pub fn handle(day: Day) {
         }
     }
 
+    if paths::scope(year).is_some() {
+        match register_bin(year, day) {
+            Ok(true) => println!(
+                "Added binary \"{}\" to Cargo.toml",
+                paths::bin_name(year, day)
+            ),
+            Ok(false) => {}
+            Err(e) => {
+                eprintln!("Failed to add binary to Cargo.toml: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
     println!("---");
-    println!("🎄 Type `cargo solve {}` to run your solution.", day);
+    println!(
+        "🎄 Type `{}` to run your solution.",
+        paths::cargo_command("solve", year, day)
+    );
 }
@@ -1,9 +1,44 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
 
-use crate::Day;
+use crate::template::bench::BenchOptions;
+use crate::template::paths;
+use crate::template::report::Format;
+use crate::template::timeout::Timeouts;
+use crate::{Day, Part, Year};
 
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// Flags of `cargo solve`, forwarded to the binary of the day.
+#[derive(Debug, Default)]
+pub struct Options {
+    pub release: bool,
+    pub time: Option<BenchOptions>,
+    /// Runs only this part.
+    pub part: Option<Part>,
+    pub submit: Option<Part>,
+    pub format: Format,
+    /// Runtime parameters as `key=value`, see [`params`](crate::template::params).
+    pub params: Vec<String>,
+    /// A file or directory to read the input from instead of the input of the day, or `-` for stdin.
+    pub input: Option<String>,
+    pub timeouts: Timeouts,
+}
+
+pub fn handle(year: Year, day: Day, options: Options) {
+    let Options {
+        release,
+        time,
+        part,
+        submit,
+        format,
+        params,
+        input,
+        timeouts,
+    } = options;
+
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--bin".to_string(),
+        paths::bin_name(year, day),
+    ];
 
     if release {
         cmd_args.push("--release".to_string());
@@ -11,13 +46,35 @@ pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
 
     cmd_args.push("--".to_string());
 
-    if let Some(submit_part) = submit_part {
+    if let Some(part) = part {
+        cmd_args.push("--part".to_string());
+        cmd_args.push(part.to_string());
+    }
+
+    if let Some(submit) = submit {
         cmd_args.push("--submit".to_string());
-        cmd_args.push(submit_part.to_string());
+        cmd_args.push(submit.to_string());
+    }
+
+    if format != Format::Text {
+        cmd_args.push("--format".to_string());
+        cmd_args.push(format.to_string());
     }
 
-    if time {
-        cmd_args.push("--time".to_string());
+    if let Some(bench_options) = time {
+        cmd_args.extend(bench_options.to_args());
+    }
+
+    cmd_args.extend(timeouts.to_args());
+
+    if let Some(input) = input {
+        cmd_args.push("--input".to_string());
+        cmd_args.push(input);
+    }
+
+    for param in params {
+        cmd_args.push("--param".to_string());
+        cmd_args.push(param);
     }
 
     let mut cmd = Command::new("cargo")
@@ -27,5 +84,8 @@ pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
         .spawn()
         .unwrap();
 
-    cmd.wait().unwrap();
+    let status = cmd.wait().unwrap();
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
 }
@@ -0,0 +1,15 @@
+use std::process;
+
+use crate::template::readme_stars;
+use crate::Year;
+
+pub fn handle(year: Year) {
+    match readme_stars::update(year, &readme_stars::collect(year)) {
+        Ok(true) => println!("Successfully updated README with stars."),
+        Ok(false) => println!("README stars are up to date."),
+        Err(_) => {
+            eprintln!("Failed to update readme with stars.");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use std::fs;
+use std::path::Path;
+
+use crate::template::input::{self, Issue};
+use crate::template::{paths, puzzle};
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+use crate::{all_days, Day, Part, Year};
+
+/// The files that exist for a day.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct DayStatus {
+    /// `src/bin/{day}.rs` exists.
+    solution: bool,
+    /// Issues of the input as found by [`input::check`], [`None`] if there is no input file.
+    input: Option<Vec<Issue>>,
+    puzzle: bool,
+    /// Number of example files, `{day}.txt` and `{day}-{part}.txt`.
+    examples: usize,
+    /// Number of parts with a confirmed answer.
+    answers: usize,
+}
+
+impl DayStatus {
+    fn collect(year: Year, day: Day) -> Self {
+        let examples_dir = format!("{}/examples", paths::data_dir(year));
+        let example_files = [
+            format!("{day}.txt"),
+            format!("{day}-1.txt"),
+            format!("{day}-2.txt"),
+        ];
+        let answers = puzzle::confirmed_answers(year, day);
+
+        Self {
+            solution: Path::new(&paths::bin_path(year, day)).exists(),
+            input: fs::read(paths::input_path(year, day))
+                .ok()
+                .map(|input| input::check(&input)),
+            puzzle: Path::new(&paths::puzzle_path(year, day)).exists(),
+            examples: example_files
+                .iter()
+                .filter(|file| Path::new(&examples_dir).join(file).exists())
+                .count(),
+            answers: Part::ALL
+                .iter()
+                .filter(|part| answers.get(**part).is_some())
+                .count(),
+        }
+    }
+
+    /// The input exists and a solution can work with it.
+    fn has_input(&self) -> bool {
+        self.input
+            .as_ref()
+            .is_some_and(|issues| !issues.iter().any(|issue| issue.is_fatal()))
+    }
+
+    /// Problems with the files of a day, together with the command that fixes them.
+    fn issues(&self, year: Year, day: Day) -> Vec<String> {
+        let mut issues = vec![];
+
+        let fatal = self.input.iter().flatten().find(|issue| issue.is_fatal());
+
+        if self.solution && self.input.is_none() {
+            issues.push(format!(
+                "solution has no input. Run `{}`.",
+                paths::cargo_command("download", year, day)
+            ));
+        } else if let (true, Some(issue)) = (self.solution, fatal) {
+            issues.push(format!(
+                "input {issue}. Run `{}`.",
+                paths::cargo_command("download", year, day)
+            ));
+        } else if self.solution && !self.puzzle {
+            issues.push(format!(
+                "solution has no puzzle description. Run `{}`.",
+                paths::cargo_command("download", year, day)
+            ));
+        }
+
+        if !self.solution && (self.puzzle || self.has_input()) {
+            issues.push(format!(
+                "puzzle has no solution. Run `{}`.",
+                paths::cargo_command("scaffold", year, day)
+            ));
+        }
+
+        if self.solution && self.puzzle && self.examples == 0 {
+            issues.push(format!(
+                "solution has no examples. Run `{}`.",
+                paths::cargo_command("examples", year, day)
+            ));
+        }
+
+        issues
+    }
+}
+
+/// Prints which files exist for every day of a year and lists days with missing files.
+pub fn handle(year: Year) {
+    println!("{ANSI_BOLD}Day  Solution  Input  Puzzle  Examples  Answers{ANSI_RESET}");
+
+    let mut issues = vec![];
+
+    for day in all_days(year) {
+        let status = DayStatus::collect(year, day);
+
+        let check = |value: bool| if value { "✓" } else { "·" };
+        let input = match &status.input {
+            None => "·",
+            Some(_) if status.has_input() => "✓",
+            Some(_) => "✗",
+        };
+        let count = |value: usize| match value {
+            0 => "·".to_string(),
+            n => n.to_string(),
+        };
+
+        println!(
+            "{day}   {:<8}  {:<5}  {:<6}  {:<8}  {}",
+            check(status.solution),
+            input,
+            check(status.puzzle),
+            count(status.examples),
+            match status.answers {
+                0 => "·".to_string(),
+                n => format!("{n}/2"),
+            },
+        );
+
+        issues.extend(
+            status
+                .issues(year, day)
+                .into_iter()
+                .map(|issue| format!("Day {day}: {issue}")),
+        );
+    }
+
+    println!();
+    if issues.is_empty() {
+        println!("No issues found.");
+    } else {
+        println!("{ANSI_BOLD}{} issue(s):{ANSI_RESET}", issues.len());
+        for issue in issues {
+            println!("{issue}");
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::DayStatus;
+    use crate::template::input::Issue;
+    use crate::{day, year};
+
+    #[test]
+    fn flags_inconsistent_days() {
+        let (year, day) = (year!(2015), day!(5));
+
+        let complete = DayStatus {
+            solution: true,
+            input: Some(vec![Issue::MissingTrailingNewline]),
+            puzzle: true,
+            examples: 1,
+            answers: 2,
+        };
+        assert!(complete.issues(year, day).is_empty());
+
+        let missing_input = DayStatus {
+            input: None,
+            ..complete.clone()
+        };
+        let issues = missing_input.issues(year, day);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].contains("no input"));
+        assert!(issues[0].contains("cargo download 05"));
+
+        let empty_input = DayStatus {
+            input: Some(vec![Issue::Empty]),
+            ..complete.clone()
+        };
+        let issues = empty_input.issues(year, day);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].contains("input is empty"));
+
+        let login_page = DayStatus {
+            input: Some(vec![Issue::ErrorPage]),
+            ..complete.clone()
+        };
+        assert!(login_page.issues(year, day)[0].contains("error page"));
+
+        let unsolved = DayStatus {
+            solution: false,
+            ..complete.clone()
+        };
+        assert!(unsolved.issues(year, day)[0].contains("cargo scaffold 05"));
+
+        assert!(DayStatus::default().issues(year, day).is_empty());
+    }
+}
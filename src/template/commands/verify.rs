@@ -0,0 +1,130 @@
+use std::path::Path;
+use std::process::{self, Command, Stdio};
+
+use crate::template::answers::{Answers, Check};
+use crate::template::paths;
+use crate::template::report::{PartReport, Status};
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+use crate::{all_days, Part, Year};
+
+/// Runs all solutions of a year against their inputs and compares the answers with `data/answers`.
+/// Exits with an error if a part returns a different answer than the confirmed one.
+/// Days without an input are skipped, since there is nothing to run them on.
+pub fn handle(year: Year, is_release: bool, jobs: Option<usize>) {
+    let mut args: Vec<String> = vec!["run".into(), "--quiet".into(), "--bin".into(), "all".into()];
+
+    if is_release {
+        args.push("--release".into());
+    }
+
+    args.extend([
+        "--".into(),
+        "--year".into(),
+        year.to_string(),
+        "--format".into(),
+        "json".into(),
+        "--no-readme".into(),
+    ]);
+
+    if let Some(jobs) = jobs {
+        args.push("--jobs".into());
+        args.push(jobs.to_string());
+    }
+
+    let output = match Command::new("cargo")
+        .args(&args)
+        .stderr(Stdio::inherit())
+        .output()
+    {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("failed to run solutions: {e}");
+            process::exit(1);
+        }
+    };
+
+    let reports: Vec<PartReport> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(PartReport::parse_line)
+        .collect();
+
+    let (mut passed, mut failed, mut skipped, mut unknown) = (0, 0, 0, 0);
+
+    for day in all_days(year) {
+        let answers = match Answers::load(year, day) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("Day {day}: {e}");
+                failed += 1;
+                continue;
+            }
+        };
+
+        let day_reports: Vec<&PartReport> = reports.iter().filter(|r| r.day == day).collect();
+        if day_reports.is_empty() && answers == Answers::default() {
+            continue;
+        }
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+
+        if !Path::new(&paths::input_path(year, day)).exists() {
+            skipped += 1;
+            println!(
+                "Skipped, input \"{}\" is missing.",
+                paths::input_path(year, day)
+            );
+            continue;
+        }
+
+        for part in Part::ALL {
+            let report = day_reports.iter().find(|r| r.part == part);
+            let answer = report
+                .filter(|r| r.status == Status::Solved)
+                .and_then(|r| r.answer.as_ref());
+
+            let check = match (answer, answers.get(part)) {
+                (Some(answer), _) => answers.check(part, answer),
+                (None, Some(expected)) => Check::Wrong {
+                    expected: expected.clone(),
+                },
+                (None, None) => Check::Unknown,
+            };
+
+            let line = match (answer, check) {
+                (Some(answer), Check::Correct) => {
+                    passed += 1;
+                    format!("✓ {answer}")
+                }
+                (Some(answer), Check::Wrong { expected }) => {
+                    failed += 1;
+                    format!("✗ {answer}, expected {expected}")
+                }
+                (None, Check::Wrong { expected }) => {
+                    failed += 1;
+                    let reason = match report {
+                        Some(report) if report.status == Status::Panicked => "panicked",
+                        Some(report) if report.status == Status::Failed => "failed",
+                        Some(report) if report.status == Status::TimedOut => "timed out",
+                        Some(_) => "no answer",
+                        None => "not run",
+                    };
+                    format!("✗ {reason}, expected {expected}")
+                }
+                (Some(answer), _) => {
+                    unknown += 1;
+                    format!("? {answer} (no confirmed answer)")
+                }
+                (None, _) => continue,
+            };
+
+            println!("Part {part}: {line}");
+        }
+    }
+
+    println!();
+    println!("{ANSI_BOLD}{passed} passed, {failed} failed{ANSI_RESET}, {skipped} skipped, {unknown} without confirmed answer.");
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,217 @@
+/// Module that extracts example inputs and their answers from a downloaded puzzle description.
+/// Examples are the code blocks introduced by the prose ("For example:"), answers are the emphasised
+/// code spans (`*142*`) of a part, of which the website marks the example result last.
+use regex::Regex;
+
+use crate::template::puzzle::{Puzzle, PuzzlePart};
+
+/// The example of one part of a puzzle.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Example {
+    pub input: Option<String>,
+    pub answer: Option<String>,
+}
+
+/// The examples of both parts. `part_two` is [`None`] until part one is solved and the puzzle is downloaded again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Examples {
+    pub part_one: Example,
+    pub part_two: Option<Example>,
+}
+
+/// Extracts the examples from a puzzle description in markdown.
+pub fn extract(puzzle: &str) -> Examples {
+    let puzzle = Puzzle::parse(puzzle);
+    let extract_part = |part: &PuzzlePart| Example {
+        input: find_example_block(&part.description),
+        answer: find_answer(&part.description),
+    };
+
+    Examples {
+        part_one: puzzle
+            .part_one
+            .as_ref()
+            .map_or(Example::default(), extract_part),
+        part_two: puzzle.part_two.as_ref().map(extract_part),
+    }
+}
+
+/// Picks the first code block that follows a paragraph mentioning an example, or the first code block.
+fn find_example_block(section: &str) -> Option<String> {
+    let mut blocks: Vec<(String, bool)> = vec![];
+    let mut block: Option<Vec<&str>> = None;
+    let mut previous_paragraph = "";
+
+    for line in section.lines() {
+        match (&mut block, line.trim_end() == "```") {
+            (None, true) => block = Some(vec![]),
+            (Some(lines), true) => {
+                let content = lines.join("\n").trim_end_matches('\n').to_string();
+                let is_example = previous_paragraph.to_lowercase().contains("example");
+                blocks.push((content, is_example));
+                block = None;
+            }
+            (Some(lines), false) => lines.push(line),
+            (None, false) if !line.trim().is_empty() => previous_paragraph = line,
+            (None, false) => {}
+        }
+    }
+
+    blocks
+        .iter()
+        .find(|(_, is_example)| *is_example)
+        .or_else(|| blocks.first())
+        .filter(|(content, _)| !content.trim().is_empty())
+        .map(|(content, _)| format!("{content}\n"))
+}
+
+fn find_answer(section: &str) -> Option<String> {
+    let re = Regex::new(r"`\*([^`*]+)\*`").unwrap();
+    re.captures_iter(section)
+        .last()
+        .map(|c| c[1].trim().to_string())
+}
+
+/// Replaces the `None` assertion of a generated test with the expected answer.
+/// Returns [`None`] if the test does not exist or already asserts a value.
+pub fn fill_test_answer(module: &str, test_name: &str, answer: &str) -> Option<String> {
+    let start = module.find(&format!("fn {test_name}()"))?;
+    let placeholder = "assert_eq!(result, None);";
+    let end = module[start..]
+        .find('}')
+        .map_or(module.len(), |i| start + i);
+    let offset = start + module[start..end].find(placeholder)?;
+
+    let digits = answer.strip_prefix('-').unwrap_or(answer);
+    let expected = if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) {
+        format!("Some({answer})")
+    } else {
+        format!("Some({answer:?}.into())")
+    };
+
+    let mut module = module.to_string();
+    module.replace_range(
+        offset..offset + placeholder.len(),
+        &format!("assert_eq!(result, {expected});"),
+    );
+    Some(module)
+}
+
+/// Points a generated test to the example file of a part, e.g. `01-2.txt`.
+pub fn use_part_example(module: &str, test_name: &str, part: u8) -> Option<String> {
+    let start = module.find(&format!("fn {test_name}()"))?;
+    let end = module[start..]
+        .find('}')
+        .map_or(module.len(), |i| start + i);
+
+    let calls = [
+        (
+            "read_file(\"examples\", DAY)",
+            format!("read_file_part(\"examples\", DAY, {part})"),
+        ),
+        (
+            "read_year_file(\"examples\", YEAR, DAY)",
+            format!("read_year_file_part(\"examples\", YEAR, DAY, {part})"),
+        ),
+    ];
+
+    let (offset, call, replacement) = calls.iter().find_map(|(call, replacement)| {
+        let offset = start + module[start..end].find(call)?;
+        Some((offset, call, replacement))
+    })?;
+
+    let mut module = module.to_string();
+    module.replace_range(offset..offset + call.len(), replacement);
+    Some(module)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{extract, fill_test_answer, use_part_example, Example};
+
+    const PUZZLE: &str = "\\--- Day 1: Trebuchet?! ---
+----------
+
+The calibration document consists of lines of text.
+
+```
+not an example
+```
+
+For example:
+
+```
+1abc2
+treb7uchet
+
+```
+
+In this example, the calibration values are `12` and `77`. Adding these together produces `*89*`.
+
+*What is the sum of all of the calibration values?*
+
+Your puzzle answer was `54597`.
+
+\\--- Part Two ---
+----------
+
+Some of the digits are *spelled out with letters*. For example:
+
+```
+two1nine
+```
+
+In this example, the calibration value is `29`, which produces `*29*`.
+";
+
+    #[test]
+    fn extracts_examples_of_both_parts() {
+        let examples = extract(PUZZLE);
+        assert_eq!(
+            examples.part_one,
+            Example {
+                input: Some("1abc2\ntreb7uchet\n".into()),
+                answer: Some("89".into()),
+            }
+        );
+        assert_eq!(
+            examples.part_two,
+            Some(Example {
+                input: Some("two1nine\n".into()),
+                answer: Some("29".into()),
+            })
+        );
+    }
+
+    #[test]
+    fn extracts_examples_of_unsolved_puzzle() {
+        let (part_one, _) = PUZZLE.split_once("Your puzzle answer").unwrap();
+        let examples = extract(part_one);
+        assert_eq!(examples.part_one.answer, Some("89".into()));
+        assert_eq!(examples.part_two, None);
+    }
+
+    #[test]
+    fn fills_generated_tests() {
+        let module = [
+            "fn test_part_one() {",
+            "    let result = part_one(&advent_of_code::template::read_file(\"examples\", DAY));",
+            "    assert_eq!(result, None);",
+            "}",
+            "fn test_part_two() {",
+            "    let result = part_two(&advent_of_code::template::read_file(\"examples\", DAY));",
+            "    assert_eq!(result, None);",
+            "}",
+        ]
+        .join("\n");
+
+        let module = fill_test_answer(&module, "test_part_two", "ABC").unwrap();
+        let module = fill_test_answer(&module, "test_part_one", "89").unwrap();
+        let module = use_part_example(&module, "test_part_two", 2).unwrap();
+
+        assert!(module.contains("    assert_eq!(result, Some(89));\n}\nfn test_part_two"));
+        assert!(module.contains("read_file_part(\"examples\", DAY, 2));"));
+        assert!(module.ends_with("    assert_eq!(result, Some(\"ABC\".into()));\n}"));
+        assert_eq!(fill_test_answer(&module, "test_part_one", "1"), None);
+    }
+}
@@ -0,0 +1,274 @@
+/// Module that reads and checks a puzzle input before a solution runs on it.
+/// Downloads can go wrong in ways that only show up as a panic deep in the parsing code of a solution,
+/// e.g. an empty file created by `scaffold` or the "please log in" page of the website.
+/// Besides the input of the day, a solution can run on a file, a directory of files or stdin, see [`Source`].
+use std::fmt::Display;
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+use crate::template::paths;
+use crate::{Day, Year};
+
+/// Bodies of pages that the website returns instead of an input.
+const ERROR_PAGES: [&str; 4] = [
+    "please log in to get your puzzle input",
+    "please don't repeatedly request this endpoint before it unlocks",
+    "404 not found",
+    "500 internal server error",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Issue {
+    Empty,
+    /// The file contains a html or error page of the website.
+    ErrorPage,
+    CrlfLineEndings,
+    MissingTrailingNewline,
+    /// The first line that contains a non-ASCII byte.
+    NonAscii {
+        line: usize,
+    },
+}
+
+impl Issue {
+    /// Whether no solution can work with the input.
+    pub fn is_fatal(self) -> bool {
+        matches!(self, Issue::Empty | Issue::ErrorPage)
+    }
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Issue::Empty => f.write_str("is empty"),
+            Issue::ErrorPage => f.write_str("contains an error page instead of the puzzle input"),
+            Issue::CrlfLineEndings => f.write_str("has CRLF line endings"),
+            Issue::MissingTrailingNewline => {
+                f.write_str("does not end with a newline and might be incomplete")
+            }
+            Issue::NonAscii { line } => write!(f, "contains non-ASCII characters on line {line}"),
+        }
+    }
+}
+
+/// Checks the contents of an input file.
+pub fn check(input: &[u8]) -> Vec<Issue> {
+    if input.iter().all(u8::is_ascii_whitespace) {
+        return vec![Issue::Empty];
+    }
+
+    let text = String::from_utf8_lossy(input).to_lowercase();
+    let start = text.trim_start();
+    if start.starts_with("<!doctype") || start.starts_with("<html") {
+        return vec![Issue::ErrorPage];
+    }
+    if ERROR_PAGES.iter().any(|page| text.contains(page)) {
+        return vec![Issue::ErrorPage];
+    }
+
+    let mut issues = vec![];
+
+    if input.windows(2).any(|w| w == b"\r\n") {
+        issues.push(Issue::CrlfLineEndings);
+    }
+
+    if !input.ends_with(b"\n") {
+        issues.push(Issue::MissingTrailingNewline);
+    }
+
+    if let Some(position) = input.iter().position(|b| !b.is_ascii()) {
+        let line = input[..position].iter().filter(|&&b| b == b'\n').count() + 1;
+        issues.push(Issue::NonAscii { line });
+    }
+
+    issues
+}
+
+/// Reads the input of a day and reports problems with it on stderr.
+/// Returns [`None`] if the input is missing or no solution can work with it.
+pub fn load(year: Year, day: Day) -> Option<String> {
+    let path = paths::input_path(year, day);
+    let download = paths::cargo_command("download", year, day);
+
+    let Ok(input) = fs::read(&path) else {
+        eprintln!("Input file \"{path}\" is missing. Run `{download}`.");
+        return None;
+    };
+
+    validate(input, &format!("file \"{path}\""), Some(&download))
+}
+
+/// Where a solution reads its input from, set with `--input`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// The input of the day in `data/inputs`.
+    Day,
+    /// A file, or a directory whose `.txt` files are run in turn.
+    Path(String),
+    /// `--input -`.
+    Stdin,
+}
+
+impl Source {
+    /// Reads `--input <path>` from the command line of a solution binary.
+    pub fn from_args(args: &[String]) -> Self {
+        let path = args
+            .iter()
+            .position(|x| x == "--input")
+            .and_then(|i| args.get(i + 1));
+
+        match path.map(String::as_str) {
+            None => Source::Day,
+            Some("-") => Source::Stdin,
+            Some(path) => Source::Path(path.to_string()),
+        }
+    }
+}
+
+/// An input that is not the input of the day, with the label that identifies its results.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabelledInput {
+    pub label: String,
+    pub text: String,
+}
+
+/// Reads the inputs of a source other than [`Source::Day`] and reports problems with them on stderr.
+/// Unusable inputs are skipped, the others are returned in the order they should run.
+pub fn load_labelled(source: &Source) -> Vec<LabelledInput> {
+    match source {
+        Source::Day => vec![],
+        Source::Stdin => {
+            let mut input = vec![];
+            if let Err(e) = io::stdin().read_to_end(&mut input) {
+                eprintln!("Failed to read the input from stdin: {e}");
+                return vec![];
+            }
+            validate(input, "from stdin", None)
+                .map(|text| LabelledInput {
+                    label: "stdin".into(),
+                    text,
+                })
+                .into_iter()
+                .collect()
+        }
+        Source::Path(path) if Path::new(path).is_dir() => {
+            let files = directory_inputs(path);
+            if files.is_empty() {
+                eprintln!("Input directory \"{path}\" contains no .txt files.");
+            }
+            files
+                .iter()
+                .filter_map(|file| load_file(file, file_label(file)))
+                .collect()
+        }
+        Source::Path(path) => load_file(path, path.clone()).into_iter().collect(),
+    }
+}
+
+/// The `.txt` files of a directory, sorted by name.
+fn directory_inputs(dir: &str) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut files: Vec<String> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "txt"))
+        .map(|path| path.to_string_lossy().into_owned())
+        .collect();
+    files.sort();
+    files
+}
+
+/// Labels a file of an input directory by its name without extension, e.g. `alice` for `data/inputs/10/alice.txt`.
+fn file_label(path: &str) -> String {
+    Path::new(path).file_stem().map_or_else(
+        || path.to_string(),
+        |stem| stem.to_string_lossy().into_owned(),
+    )
+}
+
+fn load_file(path: &str, label: String) -> Option<LabelledInput> {
+    let Ok(input) = fs::read(path) else {
+        eprintln!("Input file \"{path}\" is missing.");
+        return None;
+    };
+
+    validate(input, &format!("file \"{path}\""), None).map(|text| LabelledInput { label, text })
+}
+
+/// Checks an input and reports its issues, together with the command that downloads it again if there is one.
+fn validate(input: Vec<u8>, subject: &str, download: Option<&str>) -> Option<String> {
+    let issues = check(&input);
+
+    for issue in &issues {
+        match (issue.is_fatal(), download) {
+            (true, Some(download)) => eprintln!("Input {subject} {issue}. Run `{download}`."),
+            (true, None) => eprintln!("Input {subject} {issue}."),
+            (false, Some(download)) => eprintln!(
+                "Warning: input {subject} {issue}. Run `{download}` if the solution fails."
+            ),
+            (false, None) => eprintln!("Warning: input {subject} {issue}."),
+        }
+    }
+
+    if issues.iter().any(|issue| issue.is_fatal()) {
+        return None;
+    }
+
+    Some(String::from_utf8_lossy(&input).into_owned())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check, file_label, Issue, Source};
+
+    #[test]
+    fn accepts_regular_inputs() {
+        assert_eq!(check(b"1abc2\npqr3stu8vwx\n"), vec![]);
+    }
+
+    #[test]
+    fn rejects_empty_inputs_and_error_pages() {
+        assert_eq!(check(b""), vec![Issue::Empty]);
+        assert_eq!(check(b"\n\n"), vec![Issue::Empty]);
+        assert_eq!(
+            check(b"Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"),
+            vec![Issue::ErrorPage]
+        );
+        assert_eq!(
+            check(b"<!DOCTYPE html>\n<html lang=\"en-us\">"),
+            vec![Issue::ErrorPage]
+        );
+        assert!(Issue::ErrorPage.is_fatal());
+    }
+
+    #[test]
+    fn warns_about_formatting() {
+        assert_eq!(
+            check("1,2\r\n3,4\r\n".as_bytes()),
+            vec![Issue::CrlfLineEndings]
+        );
+        assert_eq!(check(b"1,2\n3,4"), vec![Issue::MissingTrailingNewline]);
+        assert_eq!(
+            check("1,2\n3\u{a0}4\n".as_bytes()),
+            vec![Issue::NonAscii { line: 2 }]
+        );
+        assert!(!Issue::CrlfLineEndings.is_fatal());
+    }
+
+    #[test]
+    fn reads_input_sources() {
+        let args =
+            |values: &[&str]| -> Vec<String> { values.iter().map(ToString::to_string).collect() };
+        assert_eq!(Source::from_args(&args(&["--part", "1"])), Source::Day);
+        assert_eq!(Source::from_args(&args(&["--input", "-"])), Source::Stdin);
+        assert_eq!(
+            Source::from_args(&args(&["--input", "data/inputs/10"])),
+            Source::Path("data/inputs/10".into())
+        );
+        assert_eq!(file_label("data/inputs/10/alice.txt"), "alice");
+    }
+}
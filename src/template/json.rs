@@ -0,0 +1,400 @@
+//! Tiny json reader and writer for the files and records the template produces.
+//! Supports the full json grammar. Integers keep their exact value, other numbers are represented as [`f64`].
+use std::fmt::{Display, Write};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    /// A number without fraction or exponent, e.g. a duration in nanoseconds.
+    /// Covers the full range of both [`i64`] and [`u64`].
+    Integer(i128),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    /// Builds an object from key-value pairs, keeping the order of keys.
+    pub fn object<const N: usize>(entries: [(&str, Json); N]) -> Self {
+        Json::Object(entries.into_iter().map(|(k, v)| (k.into(), v)).collect())
+    }
+
+    /// Returns the value of an object key.
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Json::Integer(n) => Some(*n as f64),
+            Json::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Json::Integer(n) => u64::try_from(*n).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Json]> {
+        match self {
+            Json::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+}
+
+impl From<&str> for Json {
+    fn from(s: &str) -> Self {
+        Json::String(s.into())
+    }
+}
+
+impl From<String> for Json {
+    fn from(s: String) -> Self {
+        Json::String(s)
+    }
+}
+
+impl From<bool> for Json {
+    fn from(b: bool) -> Self {
+        Json::Bool(b)
+    }
+}
+
+impl From<f64> for Json {
+    fn from(n: f64) -> Self {
+        Json::Number(n)
+    }
+}
+
+impl From<u64> for Json {
+    fn from(n: u64) -> Self {
+        Json::Integer(n.into())
+    }
+}
+
+impl From<i64> for Json {
+    fn from(n: i64) -> Self {
+        Json::Integer(n.into())
+    }
+}
+
+impl From<u8> for Json {
+    fn from(n: u8) -> Self {
+        Json::Integer(n.into())
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Json::Null, Into::into)
+    }
+}
+
+impl Display for Json {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Json::Null => f.write_str("null"),
+            Json::Bool(b) => write!(f, "{b}"),
+            Json::Integer(n) => write!(f, "{n}"),
+            Json::Number(n) if n.is_finite() => write!(f, "{n}"),
+            Json::Number(_) => f.write_str("null"),
+            Json::String(s) => write_string(f, s),
+            Json::Array(items) => {
+                f.write_char('[')?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                f.write_char(']')
+            }
+            Json::Object(entries) => {
+                f.write_char('{')?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut std::fmt::Formatter<'_>, s: &str) -> std::fmt::Result {
+    f.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An error which can be returned when parsing json.
+#[derive(Debug)]
+pub struct ParseError {
+    pub position: usize,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid json at position {}", self.position)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses a json document.
+pub fn parse(s: &str) -> Result<Json, ParseError> {
+    let mut parser = Parser {
+        chars: s.char_indices().collect(),
+        pos: 0,
+    };
+    let value = parser.value()?;
+    parser.whitespace();
+    if parser.pos < parser.chars.len() {
+        return Err(parser.error());
+    }
+    Ok(value)
+}
+
+struct Parser {
+    chars: Vec<(usize, char)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn error(&self) -> ParseError {
+        ParseError {
+            position: self.chars.get(self.pos).map_or(usize::MAX, |(i, _)| *i),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).map(|(_, c)| *c)
+    }
+
+    fn next(&mut self) -> Result<char, ParseError> {
+        let c = self.peek().ok_or_else(|| self.error())?;
+        self.pos += 1;
+        Ok(c)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn literal(&mut self, literal: &str, value: Json) -> Result<Json, ParseError> {
+        for c in literal.chars() {
+            self.expect(c)?;
+        }
+        Ok(value)
+    }
+
+    fn whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn value(&mut self) -> Result<Json, ParseError> {
+        self.whitespace();
+        match self.peek() {
+            Some('n') => self.literal("null", Json::Null),
+            Some('t') => self.literal("true", Json::Bool(true)),
+            Some('f') => self.literal("false", Json::Bool(false)),
+            Some('"') => self.string().map(Json::String),
+            Some('[') => self.array(),
+            Some('{') => self.object(),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.number(),
+            _ => Err(self.error()),
+        }
+    }
+
+    fn number(&mut self) -> Result<Json, ParseError> {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E'))
+        {
+            self.pos += 1;
+        }
+        let s: String = self.chars[start..self.pos].iter().map(|(_, c)| c).collect();
+        let error = ParseError {
+            position: self.chars[start].0,
+        };
+
+        if s.contains(['.', 'e', 'E']) {
+            s.parse().map(Json::Number).map_err(|_| error)
+        } else {
+            s.parse().map(Json::Integer).map_err(|_| error)
+        }
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        self.expect('"')?;
+        let mut s = String::new();
+        loop {
+            match self.next()? {
+                '"' => return Ok(s),
+                '\\' => match self.next()? {
+                    'n' => s.push('\n'),
+                    'r' => s.push('\r'),
+                    't' => s.push('\t'),
+                    'b' => s.push('\u{8}'),
+                    'f' => s.push('\u{c}'),
+                    'u' => {
+                        let mut code = 0;
+                        for _ in 0..4 {
+                            let digit = self.next()?.to_digit(16).ok_or_else(|| self.error())?;
+                            code = code * 16 + digit;
+                        }
+                        s.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+                    }
+                    c => s.push(c),
+                },
+                c => s.push(c),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Json, ParseError> {
+        self.expect('[')?;
+        let mut items = vec![];
+        self.whitespace();
+        if self.peek() == Some(']') {
+            self.pos += 1;
+            return Ok(Json::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            self.whitespace();
+            match self.next()? {
+                ',' => continue,
+                ']' => return Ok(Json::Array(items)),
+                _ => return Err(self.error()),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Json, ParseError> {
+        self.expect('{')?;
+        let mut entries = vec![];
+        self.whitespace();
+        if self.peek() == Some('}') {
+            self.pos += 1;
+            return Ok(Json::Object(entries));
+        }
+        loop {
+            self.whitespace();
+            let key = self.string()?;
+            self.whitespace();
+            self.expect(':')?;
+            entries.push((key, self.value()?));
+            self.whitespace();
+            match self.next()? {
+                ',' => continue,
+                '}' => return Ok(Json::Object(entries)),
+                _ => return Err(self.error()),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse, Json};
+
+    #[test]
+    fn round_trips_values() {
+        let value = Json::object([
+            ("answer", "a \"quoted\"\nline".into()),
+            ("part", 1u8.into()),
+            ("nanos", 74.13.into()),
+            ("status", Json::Null),
+            ("samples", Json::Array(vec![true.into(), false.into()])),
+        ]);
+        let serialized = value.to_string();
+        assert_eq!(
+            serialized,
+            r#"{"answer":"a \"quoted\"\nline","part":1,"nanos":74.13,"status":null,"samples":[true,false]}"#
+        );
+        assert_eq!(parse(&serialized).unwrap(), value);
+    }
+
+    #[test]
+    fn parses_whitespace_and_escapes() {
+        let value = parse(" { \"a\" : [ 1 , -2.5e1 ] , \"b\" : \"\\u00e9\" } ").unwrap();
+        assert_eq!(
+            value.get("a").unwrap().as_array().unwrap()[1].as_f64(),
+            Some(-25.0)
+        );
+        assert_eq!(value.get("b").unwrap().as_str(), Some("é"));
+    }
+
+    #[test]
+    fn keeps_large_integers_exact() {
+        let value = Json::object([
+            ("max", u64::MAX.into()),
+            ("min", i64::MIN.into()),
+            ("nanos", 9_007_199_254_740_993_u64.into()),
+        ]);
+        let serialized = value.to_string();
+        assert_eq!(
+            serialized,
+            r#"{"max":18446744073709551615,"min":-9223372036854775808,"nanos":9007199254740993}"#
+        );
+
+        let parsed = parse(&serialized).unwrap();
+        assert_eq!(parsed, value);
+        assert_eq!(parsed.get("max").unwrap().as_u64(), Some(u64::MAX));
+        assert_eq!(
+            parsed.get("nanos").unwrap().as_u64(),
+            Some(9_007_199_254_740_993)
+        );
+        assert_eq!(parsed.get("min").unwrap().as_u64(), None);
+        assert_eq!(parse("2.0").unwrap().as_u64(), None);
+    }
+
+    #[test]
+    fn rejects_invalid_json() {
+        assert!(parse("{\"a\": }").is_err());
+        assert!(parse("[1, 2").is_err());
+        assert!(parse("1 2").is_err());
+    }
+}
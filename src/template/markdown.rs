@@ -0,0 +1,297 @@
+//! Minimal html to markdown conversion for puzzle pages of adventofcode.com.
+//! Covers the handful of tags used in puzzle descriptions and produces the same markdown that aoc-cli writes.
+
+/// Converts a html fragment to markdown.
+#[must_use]
+pub fn from_html(html: &str) -> String {
+    let mut out = String::new();
+    let mut links: Vec<(usize, String)> = vec![];
+    let mut block_start = 0;
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut skip_depth = 0;
+
+    for token in tokenize(html) {
+        match token {
+            Token::Open(name, attrs) => match name.as_str() {
+                "script" | "style" => skip_depth += 1,
+                "h2" | "p" => block_start = out.len(),
+                "em" if !in_pre => out.push('*'),
+                "code" => {
+                    in_code = true;
+                    if !in_pre {
+                        out.push('`');
+                    }
+                }
+                "pre" => {
+                    in_pre = true;
+                    out.push_str("```\n");
+                }
+                "a" => links.push((out.len(), get_attribute(&attrs, "href").unwrap_or_default())),
+                "li" => out.push_str("* "),
+                "br" => out.push('\n'),
+                _ => {}
+            },
+            Token::Close(name) => match name.as_str() {
+                "script" | "style" => skip_depth -= 1,
+                "h2" => {
+                    if out[block_start..].starts_with("---") {
+                        out.insert(block_start, '\\');
+                    }
+                    out.push_str("\n----------\n\n");
+                }
+                "p" | "ul" | "article" => out.push_str("\n\n"),
+                "em" if !in_pre => out.push('*'),
+                "code" => {
+                    in_code = false;
+                    if !in_pre {
+                        out.push('`');
+                    }
+                }
+                "pre" => {
+                    in_pre = false;
+                    out.push_str("\n```\n\n");
+                }
+                "a" => {
+                    if let Some((start, href)) = links.pop() {
+                        out.insert(start, '[');
+                        out.push_str(&format!("]({href})"));
+                    }
+                }
+                "li" => out.push('\n'),
+                _ => {}
+            },
+            Token::Text(_) if skip_depth > 0 => {}
+            Token::Text(text) => {
+                let text = decode_entities(&text);
+                if in_pre {
+                    out.push_str(&text);
+                } else {
+                    let collapsed = collapse_whitespace(&text, out.ends_with(char::is_whitespace));
+                    if in_code {
+                        out.push_str(&collapsed);
+                    } else {
+                        out.push_str(&collapsed.replace('*', "\\*"));
+                    }
+                }
+            }
+        }
+    }
+
+    normalize_blank_lines(&out)
+}
+
+/// Returns the content of the `<main>` element, or the whole document if there is none.
+#[must_use]
+pub fn main_section(html: &str) -> &str {
+    let start = html.find("<main>").map_or(0, |i| i + "<main>".len());
+    let end = html[start..]
+        .find("</main>")
+        .map_or(html.len(), |i| start + i);
+    &html[start..end]
+}
+
+/// Returns all `<article>` elements of a document, including their tags.
+#[must_use]
+pub fn articles(html: &str) -> Vec<&str> {
+    let mut res = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(len) = rest[start..].find("</article>") else {
+            break;
+        };
+        let end = start + len + "</article>".len();
+        res.push(&rest[start..end]);
+        rest = &rest[end..];
+    }
+
+    res
+}
+
+enum Token {
+    Open(String, String),
+    Close(String),
+    Text(String),
+}
+
+fn tokenize(html: &str) -> Vec<Token> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |i| &comment[i + 3..]);
+            continue;
+        }
+
+        if rest.starts_with('<') {
+            let Some(end) = rest.find('>') else {
+                tokens.push(Token::Text(rest.into()));
+                break;
+            };
+            let tag = rest[1..end].trim_end_matches('/').trim();
+            if let Some(name) = tag.strip_prefix('/') {
+                tokens.push(Token::Close(name.trim().to_lowercase()));
+            } else if !tag.starts_with('!') {
+                let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+                tokens.push(Token::Open(name.to_lowercase(), attrs.into()));
+            }
+            rest = &rest[end + 1..];
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            tokens.push(Token::Text(rest[..end].into()));
+            rest = &rest[end..];
+        }
+    }
+
+    tokens
+}
+
+fn get_attribute(attrs: &str, name: &str) -> Option<String> {
+    let pattern = format!("{name}=\"");
+    let start = attrs.find(&pattern)? + pattern.len();
+    let end = attrs[start..].find('"')? + start;
+    Some(decode_entities(&attrs[start..end]))
+}
+
+fn decode_entities(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(i) = rest.find('&') {
+        out.push_str(&rest[..i]);
+        rest = &rest[i..];
+
+        let decoded = rest.find(';').and_then(|end| {
+            let c = match &rest[1..end] {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                "nbsp" => ' ',
+                entity => {
+                    let code = entity.strip_prefix('#')?;
+                    let code = match code.strip_prefix(['x', 'X']) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => code.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, end + 1))
+        });
+
+        match decoded {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+fn collapse_whitespace(s: &str, trim_start: bool) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut in_whitespace = trim_start;
+
+    for c in s.chars() {
+        if c.is_whitespace() {
+            if !in_whitespace {
+                out.push(' ');
+            }
+            in_whitespace = true;
+        } else {
+            out.push(c);
+            in_whitespace = false;
+        }
+    }
+
+    out
+}
+
+fn normalize_blank_lines(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut blank_lines = 0;
+
+    for line in s.trim().lines() {
+        let line = line.trim_end_matches(' ');
+        if line.is_empty() {
+            blank_lines += 1;
+            if blank_lines > 1 {
+                continue;
+            }
+        } else {
+            blank_lines = 0;
+        }
+        out.push_str(line);
+        out.push('\n');
+    }
+
+    out.trim_end().to_string()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{articles, from_html, main_section};
+
+    #[test]
+    fn converts_puzzle_description() {
+        let html = [
+            "<article class=\"day-desc\"><h2>--- Day 1: Trebuchet?! ---</h2>",
+            "<p>Check all <em>fifty stars</em> &amp; use a <a href=\"/2015/day/1\">weather machine</a>.</p>",
+            "<p>For example:</p>",
+            "<pre><code>1abc2\npqr3stu8vwx\n</code></pre>",
+            "<p>Adding these together produces <code><em>142</em></code>.</p>",
+            "</article>",
+        ]
+        .join("\n");
+
+        let expected = [
+            "\\--- Day 1: Trebuchet?! ---",
+            "----------",
+            "",
+            "Check all *fifty stars* & use a [weather machine](/2015/day/1).",
+            "",
+            "For example:",
+            "",
+            "```",
+            "1abc2",
+            "pqr3stu8vwx",
+            "",
+            "```",
+            "",
+            "Adding these together produces `*142*`.",
+        ]
+        .join("\n");
+
+        assert_eq!(from_html(&html), expected);
+    }
+
+    #[test]
+    fn escapes_asterisks_in_text() {
+        let html = "<p>They provide two gold stars: **</p>";
+        assert_eq!(from_html(html), "They provide two gold stars: \\*\\*");
+    }
+
+    #[test]
+    fn extracts_sections() {
+        let html = "<body><main><article>a</article><p>b</p><article>c</article></main></body>";
+        assert_eq!(
+            main_section(html),
+            "<article>a</article><p>b</p><article>c</article>"
+        );
+        assert_eq!(
+            articles(html),
+            vec!["<article>a</article>", "<article>c</article>"]
+        );
+    }
+}
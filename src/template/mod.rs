@@ -1,10 +1,28 @@
-use crate::Day;
+use crate::{Day, Year};
 use std::{env, fs};
 
+pub mod answers;
 pub mod aoc_cli;
+pub mod aoc_client;
+pub mod aoc_http;
+pub mod bench;
+pub mod bench_history;
 pub mod commands;
+pub mod examples;
+pub mod input;
+pub mod json;
+pub mod markdown;
+pub mod ocr;
+pub mod params;
+pub mod paths;
+pub mod puzzle;
 pub mod readme_benchmarks;
+pub mod readme_stars;
+pub mod registry;
+pub mod report;
 pub mod runner;
+pub mod submissions;
+pub mod timeout;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
@@ -31,18 +49,94 @@ pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Helper function that reads a text file of a year to a string. See [`paths`] for the layout of years.
+#[must_use]
+pub fn read_year_file(folder: &str, year: Year, day: Day) -> String {
+    let cwd = env::current_dir().unwrap();
+    let filepath = cwd.join(paths::data_file(year, folder, day, "txt"));
+    let f = fs::read_to_string(filepath);
+    f.expect("could not open input file")
+}
+
+/// Helper function that reads a text file of a year to string, appending a part suffix. E.g. like `2022/examples/01-2.txt`.
+#[must_use]
+pub fn read_year_file_part(folder: &str, year: Year, day: Day, part: u8) -> String {
+    let cwd = env::current_dir().unwrap();
+    let filepath = cwd
+        .join(paths::data_dir(year))
+        .join(folder)
+        .join(format!("{day}-{part}.txt"));
+    let f = fs::read_to_string(filepath);
+    f.expect("could not open input file")
+}
+
+/// Creates the constants `DAY` and `SOLUTION` and sets up the input and runner for each part.
+/// `SOLUTION` is the entry of the day in the [`registry`], which the `all` binary picks up.
+///
+/// `solution!(day)` belongs to the default year. `solution!(year, day)` additionally creates the constant `YEAR`.
+///
+/// By default, `part_one` and `part_two` take the input. With `solution!(day, parsed)` or `solution!(year, day, parsed)`,
+/// the day defines `parse(&str) -> Parsed` instead, which runs once, and both parts take `&Parsed`.
+/// With `solution!(day, combined)` or `solution!(year, day, combined)`, the day defines a single
+/// `solve(&str) -> (A, B)` that returns the answers of both parts.
 #[macro_export]
 macro_rules! solution {
+    ($day:expr, parsed) => {
+        $crate::solution!(@impl None, $day, parsed);
+    };
+    ($year:expr, $day:expr, parsed) => {
+        $crate::solution!(@year $year, $day, parsed);
+    };
+    ($day:expr, combined) => {
+        $crate::solution!(@impl None, $day, combined);
+    };
+    ($year:expr, $day:expr, combined) => {
+        $crate::solution!(@year $year, $day, combined);
+    };
     ($day:expr) => {
+        $crate::solution!(@impl None, $day, parts);
+    };
+    ($year:expr, $day:expr) => {
+        $crate::solution!(@year $year, $day, parts);
+    };
+    (@year $year:expr, $day:expr, $form:ident) => {
+        /// The year of the current day.
+        const YEAR: advent_of_code::Year = advent_of_code::year!($year);
+
+        const _: () = assert!(
+            $day <= YEAR.day_count(),
+            "invalid day number, the calendar of the year is shorter"
+        );
+
+        $crate::solution!(@impl Some(YEAR), $day, $form);
+    };
+    (@impl $year:expr, $day:expr, $form:ident) => {
         /// The current day.
         const DAY: advent_of_code::Day = advent_of_code::day!($day);
 
+        /// The parts of the current day, as registered in the solution registry.
+        pub const SOLUTION: advent_of_code::template::registry::Solution =
+            advent_of_code::template::registry::Solution {
+                year: $year,
+                day: DAY,
+                run: $crate::solution!(@run $form),
+            };
+
         fn main() {
-            use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            advent_of_code::template::runner::run_main(&SOLUTION);
+        }
+    };
+    (@run parts) => {
+        |input, options| {
+            advent_of_code::template::runner::run_parts(input, options, part_one, part_two)
         }
     };
+    (@run parsed) => {
+        |input, options| {
+            advent_of_code::template::runner::run_parsed(input, options, parse, part_one, part_two)
+        }
+    };
+    (@run combined) => {
+        |input, options| advent_of_code::template::runner::run_combined(input, options, solve)
+    };
 }
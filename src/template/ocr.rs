@@ -0,0 +1,180 @@
+//! Module that decodes answers drawn as ASCII-art letters, as printed by some puzzles.
+//! Supports the two fonts of advent of code: letters that are 6 pixels tall (usually 4 wide)
+//! and letters that are 10 pixels tall (6 wide). Lit pixels are `#` or `█`, everything else is dark.
+
+/// Letters that are 6 pixels tall.
+const FONT_6: [(char, [&str; 6]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Letters that are 10 pixels tall.
+#[rustfmt::skip]
+const FONT_10: [(char, [&str; 10]); 15] = [
+    ('A', ["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', ["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', [".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', [".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', ["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', ["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', ["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', ["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', ["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', ["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', ["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', ["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', ["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+/// A grid of pixels, `true` if lit.
+type Pixels = Vec<Vec<bool>>;
+
+/// Decodes ASCII-art letters to text. Returns [`None`] if the art is not made of known letters.
+///
+/// ```
+/// # use advent_of_code::template::ocr;
+/// let art = "#..#.###\n#..#..#.\n####..#.\n#..#..#.\n#..#..#.\n#..#.###";
+/// assert_eq!(ocr::decode(art).as_deref(), Some("HI"));
+/// ```
+pub fn decode(art: &str) -> Option<String> {
+    let pixels = parse(art);
+
+    match pixels.len() {
+        6 => decode_with(&pixels, &FONT_6),
+        10 => decode_with(&pixels, &FONT_10),
+        _ => None,
+    }
+}
+
+fn is_lit(c: char) -> bool {
+    c == '#' || c == '█'
+}
+
+/// Parses the art into rows of equal width, without blank rows above and below.
+fn parse(art: &str) -> Pixels {
+    let mut rows: Pixels = art
+        .lines()
+        .map(|line| line.chars().map(is_lit).collect())
+        .skip_while(|row: &Vec<bool>| !row.contains(&true))
+        .collect();
+
+    while rows.last().is_some_and(|row| !row.contains(&true)) {
+        rows.pop();
+    }
+
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    for row in &mut rows {
+        row.resize(width, false);
+    }
+
+    rows
+}
+
+/// Splits the pixels into glyphs at dark columns and looks up each glyph in the font.
+fn decode_with<const N: usize>(pixels: &Pixels, font: &[(char, [&str; N])]) -> Option<String> {
+    let width = pixels.first().map_or(0, Vec::len);
+    let is_dark = |column: usize| pixels.iter().all(|row| !row[column]);
+
+    let mut text = String::new();
+    let mut column = 0;
+
+    while column < width {
+        if is_dark(column) {
+            column += 1;
+            continue;
+        }
+
+        let start = column;
+        while column < width && !is_dark(column) {
+            column += 1;
+        }
+
+        let glyph: Pixels = pixels
+            .iter()
+            .map(|row| row[start..column].to_vec())
+            .collect();
+        let (letter, _) = font
+            .iter()
+            .find(|(_, rows)| trim_columns(&parse(&rows.join("\n"))) == glyph)?;
+        text.push(*letter);
+    }
+
+    (!text.is_empty()).then_some(text)
+}
+
+/// Removes the dark columns on both sides of a glyph.
+fn trim_columns(pixels: &Pixels) -> Pixels {
+    let width = pixels.first().map_or(0, Vec::len);
+    let is_lit = |column: &usize| pixels.iter().any(|row| row[*column]);
+    let start = (0..width).find(is_lit).unwrap_or(0);
+    let end = (0..width).rfind(is_lit).map_or(0, |i| i + 1);
+    pixels.iter().map(|row| row[start..end].to_vec()).collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{decode, FONT_10, FONT_6};
+
+    /// Draws text in a font, with one dark column between letters.
+    fn draw<const N: usize>(font: &[(char, [&str; N])], text: &str) -> String {
+        let glyphs: Vec<&[&str; N]> = text
+            .chars()
+            .map(|c| &font.iter().find(|(letter, _)| *letter == c).unwrap().1)
+            .collect();
+
+        (0..N)
+            .map(|row| {
+                glyphs
+                    .iter()
+                    .map(|glyph| format!("{}.", glyph[row]))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn decodes_small_letters() {
+        let text: String = FONT_6.iter().map(|(letter, _)| letter).collect();
+        assert_eq!(decode(&draw(&FONT_6, &text)), Some(text));
+    }
+
+    #[test]
+    fn decodes_large_letters() {
+        let text: String = FONT_10.iter().map(|(letter, _)| letter).collect();
+        assert_eq!(decode(&draw(&FONT_10, &text)), Some(text));
+    }
+
+    #[test]
+    fn decodes_block_characters() {
+        let art = draw(&FONT_6, "RZHFGJCB")
+            .replace('#', "█")
+            .replace('.', " ");
+        assert_eq!(decode(&format!("\n{art}\n")).as_deref(), Some("RZHFGJCB"));
+    }
+
+    #[test]
+    fn rejects_unknown_art() {
+        assert_eq!(decode("#..#\n.##."), None);
+        assert_eq!(decode(&draw(&FONT_6, "AB").replace("####", "#.##")), None);
+        assert_eq!(decode("1234"), None);
+    }
+}
@@ -0,0 +1,109 @@
+/// Module that gives solutions access to runtime parameters, passed with `cargo solve 11 -- --param factor=10`.
+/// Parts read them with a default, so they behave the same when no parameter is given, e.g. in `cargo all`.
+use std::collections::HashMap;
+use std::env;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// The `--param key=value` arguments of the current process.
+fn params() -> &'static HashMap<String, String> {
+    static PARAMS: OnceLock<HashMap<String, String>> = OnceLock::new();
+    PARAMS.get_or_init(|| {
+        let args: Vec<String> = env::args().collect();
+        from_args(&args)
+    })
+}
+
+fn from_args(args: &[String]) -> HashMap<String, String> {
+    args.windows(2)
+        .filter(|pair| pair[0] == "--param")
+        .filter_map(|pair| pair[1].split_once('='))
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+/// Checks that a command-line value has the form `key=value`.
+pub fn parse_param(s: &str) -> Result<String, &'static str> {
+    match s.split_once('=') {
+        Some((key, _)) if !key.is_empty() => Ok(s.to_string()),
+        _ => Err("expecting a parameter like `factor=10`"),
+    }
+}
+
+/// Whether any parameter was passed. Answers of such a run belong to a variant of the puzzle.
+pub fn is_set() -> bool {
+    !params().is_empty()
+}
+
+/// Converts the parameters back to the `--param key=value` arguments they were read from.
+pub fn to_args() -> Vec<String> {
+    params()
+        .iter()
+        .flat_map(|(key, value)| ["--param".into(), format!("{key}={value}")])
+        .collect()
+}
+
+/// Reads the parameter `key`, or returns `default` if it was not passed.
+/// Panics if the value cannot be parsed, which fails the part that reads it.
+///
+/// ```
+/// # use advent_of_code::template::params;
+/// let factor: u64 = params::get("factor", 1_000_000);
+/// assert_eq!(factor, 1_000_000);
+/// ```
+#[track_caller]
+pub fn get<T: FromStr>(key: &str, default: T) -> T
+where
+    T::Err: Display,
+{
+    parse(params(), key, default)
+}
+
+#[track_caller]
+fn parse<T: FromStr>(params: &HashMap<String, String>, key: &str, default: T) -> T
+where
+    T::Err: Display,
+{
+    let Some(value) = params.get(key) else {
+        return default;
+    };
+
+    match value.parse() {
+        Ok(value) => value,
+        Err(e) => panic!("invalid value `{value}` for parameter `{key}`: {e}"),
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{from_args, parse, parse_param};
+
+    #[test]
+    fn reads_params_with_defaults() {
+        let args: Vec<String> = ["20", "--param", "presses=10", "--param", "feed=tj"]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        let params = from_args(&args);
+
+        assert_eq!(parse(&params, "presses", 1000_usize), 10);
+        assert_eq!(parse(&params, "feed", String::from("rx")), "tj");
+        assert_eq!(parse(&params, "iterations", 6000_usize), 6000);
+    }
+
+    #[test]
+    fn validates_params() {
+        assert_eq!(parse_param("factor=10"), Ok("factor=10".into()));
+        assert_eq!(parse_param("feed="), Ok("feed=".into()));
+        assert!(parse_param("factor").is_err());
+        assert!(parse_param("=10").is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid value `ten` for parameter `factor`")]
+    fn rejects_invalid_values() {
+        let params = from_args(&["--param".into(), "factor=ten".into()]);
+        parse(&params, "factor", 1_u64);
+    }
+}
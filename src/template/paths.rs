@@ -0,0 +1,71 @@
+/// Locations of solutions and their data.
+/// The default year, set by `AOC_YEAR` in `.cargo/config.toml`, uses the flat layout of the template
+/// (`src/bin/{day}.rs`, `data/inputs/{day}.txt`). Other years live in year directories
+/// (`src/bin/{year}/{day}.rs`, `data/{year}/inputs/{day}.txt`).
+use std::env;
+
+use crate::{Day, Year};
+
+/// The year configured in `AOC_YEAR`.
+pub fn default_year() -> Option<Year> {
+    env::var("AOC_YEAR").ok()?.parse().ok()
+}
+
+/// Whether a year uses the flat layout.
+pub fn is_default_year(year: Year) -> bool {
+    default_year() == Some(year)
+}
+
+/// The year a year directory is named after, [`None`] for the default year.
+pub fn scope(year: Year) -> Option<Year> {
+    (!is_default_year(year)).then_some(year)
+}
+
+/// Directory of the data of a year, e.g. `data` or `data/2022`.
+pub fn data_dir(year: Year) -> String {
+    match scope(year) {
+        Some(year) => format!("data/{year}"),
+        None => "data".into(),
+    }
+}
+
+/// Path of a data file of a day, e.g. `data/inputs/01.txt` or `data/2022/inputs/01.txt`.
+pub fn data_file(year: Year, folder: &str, day: Day, extension: &str) -> String {
+    format!("{}/{folder}/{day}.{extension}", data_dir(year))
+}
+
+pub fn input_path(year: Year, day: Day) -> String {
+    data_file(year, "inputs", day, "txt")
+}
+
+pub fn puzzle_path(year: Year, day: Day) -> String {
+    data_file(year, "puzzles", day, "md")
+}
+
+/// Name of the binary of a day, e.g. `01` or `2022-01`.
+pub fn bin_name(year: Year, day: Day) -> String {
+    match scope(year) {
+        Some(year) => format!("{year}-{day}"),
+        None => day.to_string(),
+    }
+}
+
+/// Path of the solution of a day, e.g. `src/bin/01.rs` or `src/bin/2022/01.rs`.
+pub fn bin_path(year: Year, day: Day) -> String {
+    scoped_bin_path(scope(year), day)
+}
+
+pub(crate) fn scoped_bin_path(scope: Option<Year>, day: Day) -> String {
+    match scope {
+        Some(year) => format!("src/bin/{year}/{day}.rs"),
+        None => format!("src/bin/{day}.rs"),
+    }
+}
+
+/// A cargo command for a day, with `--year` for years other than the default year, e.g. `cargo download 01 --year 2022`.
+pub fn cargo_command(command: &str, year: Year, day: Day) -> String {
+    match scope(year) {
+        Some(year) => format!("cargo {command} {day} --year {year}"),
+        None => format!("cargo {command} {day}"),
+    }
+}
@@ -0,0 +1,182 @@
+/// Module that parses a downloaded puzzle description in `data/puzzles/{day}.md`, or the `puzzles` directory of its year.
+/// Once a part is solved, the website adds "Your puzzle answer was `X`." below its description,
+/// which is picked up as the confirmed answer of that part.
+use std::fs;
+
+use crate::template::answers::{self, Answers};
+use crate::template::paths;
+use crate::{Answer, Day, Part, Year};
+
+const ANSWER_PREFIX: &str = "Your puzzle answer was";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzlePart {
+    pub description: String,
+    pub answer: Option<Answer>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Puzzle {
+    pub title: Option<String>,
+    pub part_one: Option<PuzzlePart>,
+    /// Only available after part one was solved and the puzzle was downloaded again.
+    pub part_two: Option<PuzzlePart>,
+}
+
+impl Puzzle {
+    /// Loads the puzzle description of a day. Returns [`None`] if it was not downloaded yet.
+    pub fn load(year: Year, day: Day) -> Option<Self> {
+        fs::read_to_string(paths::puzzle_path(year, day))
+            .ok()
+            .map(|s| Self::parse(&s))
+    }
+
+    pub fn parse(s: &str) -> Self {
+        let mut title = None;
+        let mut sections: Vec<Vec<&str>> = vec![];
+
+        let mut lines = s.lines().peekable();
+        while let Some(line) = lines.next() {
+            let header = line.trim_start_matches('\\');
+            if header.starts_with("--- ") && header.ends_with(" ---") {
+                if header.starts_with("--- Day ") {
+                    title = header
+                        .split_once(": ")
+                        .map(|(_, t)| t.trim_end_matches('-').trim().to_string());
+                }
+                // skip the underline of the header.
+                lines.next_if(|l| l.chars().all(|c| c == '-') && !l.is_empty());
+                sections.push(vec![]);
+            } else if let Some(section) = sections.last_mut() {
+                section.push(line);
+            }
+        }
+
+        let mut parts = sections.iter().map(|lines| parse_part(lines));
+
+        Self {
+            title,
+            part_one: parts.next(),
+            part_two: parts.next(),
+        }
+    }
+
+    pub fn get(&self, part: Part) -> Option<&PuzzlePart> {
+        match part {
+            Part::One => self.part_one.as_ref(),
+            Part::Two => self.part_two.as_ref(),
+        }
+    }
+
+    /// The answers recorded in the description.
+    pub fn answers(&self) -> Answers {
+        let answer = |part: &Option<PuzzlePart>| part.as_ref().and_then(|p| p.answer.clone());
+        Answers {
+            part_1: answer(&self.part_one),
+            part_2: answer(&self.part_two),
+        }
+    }
+}
+
+fn parse_part(lines: &[&str]) -> PuzzlePart {
+    let answer_line = lines.iter().position(|l| l.starts_with(ANSWER_PREFIX));
+
+    let description = lines[..answer_line.unwrap_or(lines.len())]
+        .join("\n")
+        .trim()
+        .to_string();
+
+    let answer = answer_line.map(|i| {
+        lines[i][ANSWER_PREFIX.len()..]
+            .trim()
+            .trim_end_matches('.')
+            .trim_matches('`')
+            .into()
+    });
+
+    PuzzlePart {
+        description,
+        answer,
+    }
+}
+
+/// Confirmed answers of a day: the answers file, completed by the answers in the puzzle description.
+pub fn confirmed_answers(year: Year, day: Day) -> Answers {
+    let mut answers = Answers::load(year, day).unwrap_or_default();
+    if let Some(puzzle) = Puzzle::load(year, day) {
+        answers.merge(&puzzle.answers());
+    }
+    answers
+}
+
+/// Stores the answers found in the puzzle description of a day in its answers file.
+/// Returns the number of parts with a recorded answer.
+pub fn store_answers(year: Year, day: Day) -> Result<usize, answers::Error> {
+    let Some(puzzle) = Puzzle::load(year, day) else {
+        return Ok(0);
+    };
+
+    let recorded = puzzle.answers();
+    let mut answers = Answers::load(year, day)?;
+    if answers.merge(&recorded) {
+        answers.save(year, day)?;
+    }
+
+    Ok([&recorded.part_1, &recorded.part_2]
+        .iter()
+        .filter(|a| a.is_some())
+        .count())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Puzzle;
+
+    const PUZZLE: &str = "\\--- Day 1: Trebuchet?! ---
+----------
+
+Something is wrong with global snow production.
+
+*What is the sum of all of the calibration values?*
+
+Your puzzle answer was `54597`.
+
+\\--- Part Two ---
+----------
+
+Some of the digits are *spelled out with letters*.
+
+Your puzzle answer was `54504`.
+
+Both parts of this puzzle are complete! They provide two gold stars: \\*\\*
+";
+
+    #[test]
+    fn parses_solved_puzzle() {
+        let puzzle = Puzzle::parse(PUZZLE);
+        assert_eq!(puzzle.title.as_deref(), Some("Trebuchet?!"));
+
+        let part_one = puzzle.part_one.unwrap();
+        assert_eq!(
+            part_one.description,
+            "Something is wrong with global snow production.\n\n*What is the sum of all of the calibration values?*"
+        );
+        assert_eq!(part_one.answer, Some("54597".into()));
+
+        let part_two = puzzle.part_two.unwrap();
+        assert_eq!(
+            part_two.description,
+            "Some of the digits are *spelled out with letters*."
+        );
+        assert_eq!(part_two.answer, Some("54504".into()));
+    }
+
+    #[test]
+    fn parses_unsolved_puzzle() {
+        let (unsolved, _) = PUZZLE.split_once("Your puzzle answer").unwrap();
+        let puzzle = Puzzle::parse(unsolved);
+        assert_eq!(puzzle.part_one.unwrap().answer, None);
+        assert_eq!(puzzle.part_two, None);
+        assert_eq!(Puzzle::parse("").title, None);
+    }
+}
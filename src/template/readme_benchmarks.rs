@@ -1,8 +1,11 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
+/// Years other than the default year get their own table, e.g. `<!--- benchmarking table 2022 --->`.
 use std::{fs, io};
 
-use crate::Day;
+use crate::template::bench::Stats;
+use crate::template::paths;
+use crate::{Day, Part, Year};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -21,23 +24,53 @@ impl From<std::io::Error> for Error {
 #[derive(Clone)]
 pub struct Timings {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    /// Only present for solutions that parse their input once.
+    pub parse: Option<Stats>,
+    pub part_1: Option<Stats>,
+    pub part_2: Option<Stats>,
+    /// Both parts were solved at once, `part_1` holds the time of both.
+    pub combined: bool,
     pub total_nanos: f64,
 }
 
+impl Timings {
+    pub fn get(&self, part: Part) -> Option<&Stats> {
+        match part {
+            Part::One => self.part_1.as_ref(),
+            Part::Two => self.part_2.as_ref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, stats: Stats) {
+        match part {
+            Part::One => self.part_1 = Some(stats),
+            Part::Two => self.part_2 = Some(stats),
+        }
+    }
+}
+
 pub struct TablePosition {
     pos_start: usize,
     pos_end: usize,
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(scope: Option<Year>, day: Day) -> String {
+    format!("./{}", paths::scoped_bin_path(scope, day))
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Marker of the table of a year directory, e.g. `<!--- benchmarking table 2022 --->`.
+/// The default year (`scope` is [`None`]) uses the plain marker.
+pub(crate) fn year_marker(marker: &str, scope: Option<Year>) -> String {
+    match scope {
+        Some(year) => marker.replace(" --->", &format!(" {year} --->")),
+        None => marker.into(),
+    }
+}
+
+/// Locates the section of the readme that is enclosed by two markers, or a single marker if it was not generated yet.
+pub(crate) fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -58,46 +91,90 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn format_stats(stats: Option<&Stats>) -> String {
+    stats.map_or_else(|| "-".into(), |s| format!("{:.1?}", s.headline()))
+}
+
+fn construct_table(
+    prefix: &str,
+    scope: Option<Year>,
+    timings: Vec<Timings>,
+    total_millis: f64,
+) -> String {
+    let marker = year_marker(MARKER, scope);
+    let header = match scope {
+        Some(year) => format!("{prefix} {year} Benchmarks"),
+        None => format!("{prefix} Benchmarks"),
+    };
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
+        marker.clone(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        "| Day | Parse | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: | :---:  |".into(),
     ];
 
     for timing in timings {
-        let path = get_path_for_bin(timing.day);
+        let path = get_path_for_bin(scope, timing.day);
+        let part_2 = if timing.combined {
+            "_with part 1_".to_string()
+        } else {
+            format!("`{}`", format_stats(timing.get(Part::Two)))
+        };
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}]({}) | `{}` | `{}` | {} |",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            format_stats(timing.parse.as_ref()),
+            format_stats(timing.get(Part::One)),
+            part_2
         ));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker);
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
+/// Replaces the section of `marker` with `table`, which is expected to be enclosed by the marker.
+pub(crate) fn replace_table(s: &mut String, marker: &str, table: &str) -> Result<(), Error> {
+    let positions = locate_table(s, marker)?;
+    s.replace_range(positions.pos_start..positions.pos_end, table);
     Ok(())
 }
 
-pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
+/// Replaces the table of a year directory, which is added below the table of `marker` if the readme has none yet.
+pub(crate) fn replace_year_table(
+    s: &mut String,
+    marker: &str,
+    scope: Option<Year>,
+    table: &str,
+) -> Result<(), Error> {
+    let year_marker = year_marker(marker, scope);
+    if !s.contains(&year_marker) {
+        let pos_end = locate_table(s, marker)?.pos_end;
+        s.insert_str(pos_end, &format!("\n\n{year_marker}"));
+    }
+    replace_table(s, &year_marker, table)
+}
+
+fn update_content(
+    s: &mut String,
+    scope: Option<Year>,
+    timings: Vec<Timings>,
+    total_millis: f64,
+) -> Result<(), Error> {
+    let table = construct_table("##", scope, timings, total_millis);
+    replace_year_table(s, MARKER, scope, &table)
+}
+
+pub fn update(year: Year, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, paths::scope(year), timings, total_millis)?;
     fs::write(path, &readme)?;
     Ok(())
 }
@@ -105,26 +182,38 @@ pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{update_content, Timings, MARKER};
-    use crate::day;
+    use crate::template::bench::Stats;
+    use crate::{day, year};
+    use std::time::Duration;
+
+    fn millis(value: u64) -> Option<Stats> {
+        Some(Stats::single(Duration::from_millis(value)))
+    }
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
                 day: day!(1),
-                part_1: Some("10ms".into()),
-                part_2: Some("20ms".into()),
+                parse: millis(5),
+                part_1: millis(10),
+                part_2: millis(20),
+                combined: false,
                 total_nanos: 3e+10,
             },
             Timings {
                 day: day!(2),
-                part_1: Some("30ms".into()),
-                part_2: Some("40ms".into()),
+                parse: None,
+                part_1: millis(30),
+                part_2: millis(40),
+                combined: false,
                 total_nanos: 7e+10,
             },
             Timings {
                 day: day!(4),
-                part_1: Some("40ms".into()),
-                part_2: Some("50ms".into()),
+                parse: None,
+                part_1: millis(40),
+                part_2: None,
+                combined: true,
                 total_nanos: 9e+10,
             },
         ]
@@ -134,28 +223,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, None, get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, None, get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, None, get_mock_timings(), 190.0).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, None, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, None, get_mock_timings(), 190.0).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -163,18 +252,18 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, None, get_mock_timings(), 190.0).unwrap();
         let expected = [
             "foo",
             "bar",
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/01.rs) | `5.0ms` | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/02.rs) | `-` | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/04.rs) | `-` | `40.0ms` | _with part 1_ |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -183,4 +272,19 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn adds_year_benchmarks_below_default_table() {
+        let mut s = format!("foo\n{}\n{}\nbaz", MARKER, MARKER);
+        let scope = Some(year!(2022));
+        update_content(&mut s, scope, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, scope, get_mock_timings(), 190.0).unwrap();
+
+        let marker = "<!--- benchmarking table 2022 --->";
+        assert_eq!(s.matches(marker).count(), 2);
+        assert_eq!(s.matches("## 2022 Benchmarks").count(), 1);
+        assert!(s.contains("| [Day 1](./src/bin/2022/01.rs) | `5.0ms` | `10.0ms` | `20.0ms` |"));
+        assert!(s.starts_with(&format!("foo\n{MARKER}\n{MARKER}\n\n{marker}\n")));
+        assert!(s.ends_with(&format!("{marker}\nbaz")));
+    }
 }
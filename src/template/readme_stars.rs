@@ -0,0 +1,164 @@
+/// Module that updates the readme with a table of collected stars.
+/// The table mirrors the one generated by the `advent-readme-stars` action, but is built from local data:
+/// puzzle titles from `data/puzzles` and stars from the submission ledger and solved puzzle descriptions.
+/// Like the benchmarks, years other than the default year get their own table.
+use std::fs;
+
+use crate::template::paths;
+use crate::template::puzzle::Puzzle;
+use crate::template::readme_benchmarks::{replace_year_table, year_marker, Error};
+use crate::template::submissions::{Ledger, Verdict};
+use crate::{all_days, Day, Part, Year};
+
+static MARKER: &str = "<!--- advent_readme_stars table --->";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stars {
+    pub day: Day,
+    pub title: Option<String>,
+    pub part_1: bool,
+    pub part_2: bool,
+}
+
+/// Collects the stars of every day of a year that has a downloaded puzzle or a star.
+pub fn collect(year: Year) -> Vec<Stars> {
+    all_days(year)
+        .filter_map(|day| {
+            let puzzle = Puzzle::load(year, day).unwrap_or_default();
+            let answers = puzzle.answers();
+            let ledger = Ledger::load(year, day).unwrap_or_default();
+            let is_correct = |part: Part| {
+                ledger
+                    .submissions
+                    .iter()
+                    .any(|s| s.part == part && s.verdict == Verdict::Correct)
+            };
+
+            let stars = Stars {
+                day,
+                title: puzzle.title,
+                part_1: answers.part_1.is_some() || is_correct(Part::One),
+                part_2: answers.part_2.is_some() || is_correct(Part::Two),
+            };
+
+            (stars.title.is_some() || stars.part_1 || stars.part_2).then_some(stars)
+        })
+        .collect()
+}
+
+fn construct_table(year: Year, scope: Option<Year>, stars: &[Stars]) -> String {
+    let marker = year_marker(MARKER, scope);
+
+    let mut lines: Vec<String> = vec![
+        marker.clone(),
+        format!("## {year} Results"),
+        String::new(),
+        "| Day | Title | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: | :---: |".into(),
+    ];
+
+    let star = |is_solved: bool| if is_solved { "⭐" } else { "" };
+
+    for day in stars {
+        let link = format!(
+            "[Day {}](https://adventofcode.com/{year}/day/{})",
+            day.day.into_inner(),
+            day.day.into_inner()
+        );
+
+        lines.push(format!(
+            "| {link} | {} | {} | {} |",
+            day.title.as_deref().unwrap_or("-"),
+            star(day.part_1),
+            star(day.part_2)
+        ));
+    }
+
+    let total = stars
+        .iter()
+        .map(|s| usize::from(s.part_1) + usize::from(s.part_2))
+        .sum::<usize>();
+
+    lines.push(String::new());
+    lines.push(format!("**Total: {total} ⭐**"));
+    lines.push(marker);
+
+    lines.join("\n")
+}
+
+fn update_content(
+    s: &mut String,
+    year: Year,
+    scope: Option<Year>,
+    stars: &[Stars],
+) -> Result<(), Error> {
+    replace_year_table(s, MARKER, scope, &construct_table(year, scope, stars))
+}
+
+/// Updates the star table of a year in the readme. Returns `true` if the table changed.
+pub fn update(year: Year, stars: &[Stars]) -> Result<bool, Error> {
+    let path = "README.md";
+    let original = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    let scope = paths::scope(year);
+
+    // other years only get a table once they have something to show.
+    if scope.is_some() && stars.is_empty() && !original.contains(&year_marker(MARKER, scope)) {
+        return Ok(false);
+    }
+
+    let mut readme = original.clone();
+    update_content(&mut readme, year, scope, stars)?;
+
+    if readme == original {
+        return Ok(false);
+    }
+
+    fs::write(path, &readme)?;
+    Ok(true)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{update_content, Stars, MARKER};
+    use crate::{day, year};
+
+    fn get_mock_stars() -> Vec<Stars> {
+        vec![
+            Stars {
+                day: day!(1),
+                title: Some("Trebuchet?!".into()),
+                part_1: true,
+                part_2: true,
+            },
+            Stars {
+                day: day!(4),
+                title: None,
+                part_1: true,
+                part_2: false,
+            },
+        ]
+    }
+
+    #[test]
+    fn format_stars() {
+        let mut s = format!("foo\n{}\nbar", MARKER);
+        update_content(&mut s, year!(2023), None, &get_mock_stars()).unwrap();
+        update_content(&mut s, year!(2023), None, &get_mock_stars()).unwrap();
+        let expected = [
+            "foo",
+            "<!--- advent_readme_stars table --->",
+            "## 2023 Results",
+            "",
+            "| Day | Title | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2023/day/1) | Trebuchet?! | ⭐ | ⭐ |",
+            "| [Day 4](https://adventofcode.com/2023/day/4) | - | ⭐ |  |",
+            "",
+            "**Total: 3 ⭐**",
+            "<!--- advent_readme_stars table --->",
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+}
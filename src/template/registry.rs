@@ -0,0 +1,162 @@
+/// Library-level table of solutions, filled by the `solution!` macro of each day.
+/// The `all` binary includes every solution in `src/bin` and runs them from this table in a single process.
+use std::collections::BTreeMap;
+use std::error::Error;
+
+use crate::template::paths;
+use crate::template::report::DayReport;
+use crate::template::runner::RunOptions;
+use crate::{Answer, Day, Year};
+
+/// The outcome of a part: an answer, no answer, or an error message that includes its source chain.
+pub type PartResult = Result<Option<Answer>, String>;
+
+/// Type-erased runner of a solution, created by the `solution!` macro.
+pub type RunFn = fn(&str, &RunOptions) -> DayReport;
+
+/// Return types of solution parts: `Option<T>` and `Result<T, E>`, where `T` converts into an [`Answer`].
+pub trait PartOutput {
+    fn into_part_result(self) -> PartResult;
+}
+
+impl<T: Into<Answer>> PartOutput for Option<T> {
+    fn into_part_result(self) -> PartResult {
+        Ok(self.map(Into::into))
+    }
+}
+
+impl<T: Into<Answer>, E: Error> PartOutput for Result<T, E> {
+    fn into_part_result(self) -> PartResult {
+        self.map(|answer| Some(answer.into()))
+            .map_err(|e| describe_error(&e))
+    }
+}
+
+/// Formats an error followed by its sources, one per line.
+fn describe_error(error: &dyn Error) -> String {
+    let mut description = error.to_string();
+    let mut source = error.source();
+    while let Some(error) = source {
+        description.push_str(&format!("\ncaused by: {error}"));
+        source = error.source();
+    }
+    description
+}
+
+/// The parts of one day, as registered by the `solution!` macro.
+#[derive(Debug, Clone, Copy)]
+pub struct Solution {
+    /// The year of the solution, [`None`] for solutions of the default year.
+    pub year: Option<Year>,
+    pub day: Day,
+    pub run: RunFn,
+}
+
+impl Solution {
+    /// The year of the solution, resolving the default year.
+    pub fn year(&self) -> Option<Year> {
+        self.year.or_else(paths::default_year)
+    }
+}
+
+/// Solutions keyed by [`Year`] and [`Day`].
+#[derive(Debug, Default)]
+pub struct Solutions {
+    table: BTreeMap<(Option<Year>, Day), Solution>,
+}
+
+impl Solutions {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a solution to the table, replacing a previous solution for the same day.
+    pub fn register(&mut self, solution: Solution) {
+        self.table.insert((solution.year, solution.day), solution);
+    }
+
+    #[must_use]
+    pub fn get(&self, year: Year, day: Day) -> Option<&Solution> {
+        self.table.get(&(Some(year), day)).or_else(|| {
+            paths::is_default_year(year)
+                .then(|| self.table.get(&(None, day)))
+                .flatten()
+        })
+    }
+
+    /// Iterates over the registered solutions, in day order within a year.
+    pub fn iter(&self) -> impl Iterator<Item = &Solution> {
+        self.table.values()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PartOutput, Solution, Solutions};
+    use crate::template::report::DayReport;
+    use crate::{day, year};
+    use std::num::ParseIntError;
+
+    const SOLUTION: Solution = Solution {
+        year: Some(year!(2022)),
+        day: day!(2),
+        run: |_, options| DayReport::new(options.day),
+    };
+
+    #[test]
+    fn registers_solutions_by_day() {
+        let mut solutions = Solutions::new();
+        solutions.register(Solution {
+            day: day!(5),
+            ..SOLUTION
+        });
+        solutions.register(SOLUTION);
+
+        assert_eq!(solutions.len(), 2);
+        assert!(solutions.get(year!(2022), day!(1)).is_none());
+        assert!(solutions.get(year!(2021), day!(2)).is_none());
+        assert_eq!(solutions.get(year!(2022), day!(2)).unwrap().day, day!(2));
+
+        let days: Vec<_> = solutions.iter().map(|s| s.day).collect();
+        assert_eq!(days, vec![day!(2), day!(5)]);
+    }
+
+    #[derive(Debug)]
+    struct GridParseError(ParseIntError);
+
+    impl std::fmt::Display for GridParseError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("invalid grid")
+        }
+    }
+
+    impl std::error::Error for GridParseError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[test]
+    fn converts_part_outputs() {
+        assert_eq!(Some(42_u32).into_part_result(), Ok(Some("42".into())));
+        assert_eq!(None::<u32>.into_part_result(), Ok(None));
+        assert_eq!("7".parse::<u32>().into_part_result(), Ok(Some("7".into())));
+
+        let error = "x".parse::<u32>().map_err(GridParseError);
+        assert_eq!(
+            error.into_part_result(),
+            Err("invalid grid\ncaused by: invalid digit found in string".into())
+        );
+    }
+}
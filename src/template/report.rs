@@ -0,0 +1,563 @@
+/// Results of solution parts, rendered for the terminal or as json records via `--format json`.
+/// In json mode, every line of stdout that is a json object with a `part` key is one record.
+/// Solutions that parse their input once or solve both parts at once also print a record with a `phase` key
+/// before the parts, see [`PhaseReport`].
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::bench::Stats;
+use crate::template::json::{self, Json};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::{Answer, Day, Part};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+impl Format {
+    /// Reads `--format <format>` from the command line of a solution binary.
+    pub fn from_args(args: &[String]) -> Self {
+        args.iter()
+            .position(|x| x == "--format")
+            .and_then(|i| args.get(i + 1))
+            .and_then(|x| x.parse().ok())
+            .unwrap_or_default()
+    }
+}
+
+impl FromStr for Format {
+    type Err = FormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(FormatFromStrError),
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Format::Text => f.write_str("text"),
+            Format::Json => f.write_str("json"),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`Format`].
+#[derive(Debug)]
+pub struct FormatFromStrError;
+
+impl std::error::Error for FormatFromStrError {}
+
+impl Display for FormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting `text` or `json`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The part returned an answer.
+    Solved,
+    /// The part returned [`None`].
+    Unsolved,
+    /// The part returned an error, see [`PartReport::error`].
+    Failed,
+    /// The part panicked, see [`PartReport::error`].
+    Panicked,
+    /// The part ran past its timeout and was stopped, see [`Timeouts`](crate::template::timeout::Timeouts).
+    TimedOut,
+}
+
+impl Status {
+    /// Whether the part did not run to completion, which fails the run.
+    pub fn is_failure(self) -> bool {
+        matches!(self, Status::Failed | Status::Panicked | Status::TimedOut)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::Failed => "failed",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timed_out",
+        }
+    }
+
+    fn from_json(s: &str) -> Option<Self> {
+        match s {
+            "solved" => Some(Status::Solved),
+            "unsolved" => Some(Status::Unsolved),
+            "failed" => Some(Status::Failed),
+            "panicked" => Some(Status::Panicked),
+            "timed_out" => Some(Status::TimedOut),
+            _ => None,
+        }
+    }
+}
+
+/// The outcome of running one part of a solution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartReport {
+    pub day: Day,
+    pub part: Part,
+    /// The label of the input the part ran on, [`None`] for the input of the day.
+    pub input: Option<String>,
+    pub status: Status,
+    pub answer: Option<Answer>,
+    /// The confirmed answer of the part, if known.
+    pub expected: Option<Answer>,
+    /// Why the part failed, e.g. the location and message of a panic.
+    pub error: Option<String>,
+    /// [`None`] if the part was timed together with the other part, see [`Phase::Solve`].
+    pub stats: Option<Stats>,
+}
+
+impl PartReport {
+    pub fn to_json(&self) -> Json {
+        Json::object([
+            ("day", self.day.into_inner().into()),
+            ("part", self.part.into_inner().into()),
+            ("input", self.input.as_deref().into()),
+            ("status", self.status.as_str().into()),
+            ("answer", self.answer.as_ref().map(Answer::to_json).into()),
+            (
+                "expected",
+                self.expected.as_ref().map(Answer::to_json).into(),
+            ),
+            ("error", self.error.as_deref().into()),
+            ("duration", self.stats.as_ref().map(Stats::to_json).into()),
+        ])
+    }
+
+    pub fn from_json(value: &Json) -> Option<Self> {
+        let day = value.get("day")?.as_u64()?;
+        Some(Self {
+            day: Day::new(day.try_into().ok()?)?,
+            part: Part::new(value.get("part")?.as_u64()?.try_into().ok()?)?,
+            input: value
+                .get("input")
+                .and_then(Json::as_str)
+                .map(ToString::to_string),
+            status: Status::from_json(value.get("status")?.as_str()?)?,
+            answer: Answer::from_json(value.get("answer")?),
+            expected: value.get("expected").and_then(Answer::from_json),
+            error: value
+                .get("error")
+                .and_then(Json::as_str)
+                .map(ToString::to_string),
+            stats: value.get("duration").and_then(Stats::from_json),
+        })
+    }
+
+    /// Parses a line of output of a solution binary. Returns [`None`] if the line is not a record.
+    pub fn parse_line(line: &str) -> Option<Self> {
+        if !line.starts_with('{') {
+            return None;
+        }
+        Self::from_json(&json::parse(line).ok()?)
+    }
+
+    /// Renders the report like the runner prints it to the terminal.
+    pub fn to_text(&self) -> String {
+        let part = format!("Part {}", self.part);
+        let duration = self.stats.as_ref().map(format_duration).unwrap_or_default();
+
+        let check = self.format_check();
+
+        let mut lines = match (&self.answer, self.status) {
+            (Some(answer), Status::Solved) if answer.is_multiline() => {
+                let letters = answer
+                    .letters()
+                    .map(|letters| format!("{ANSI_BOLD}{letters}{ANSI_RESET}"))
+                    .unwrap_or_default();
+                vec![
+                    format!("{part}: ▼ {letters}{duration}{check}"),
+                    answer.to_string(),
+                ]
+            }
+            (Some(answer), Status::Solved) => {
+                vec![format!(
+                    "{part}: {ANSI_BOLD}{answer}{ANSI_RESET}{duration}{check}"
+                )]
+            }
+            (_, Status::Failed) => {
+                let error = self.error.as_deref().unwrap_or("unknown error");
+                // the source chain of the error follows on indented lines.
+                vec![format!(
+                    "{part}: ✖ failed: {}",
+                    error.replace('\n', "\n        ")
+                )]
+            }
+            (_, Status::Panicked) => vec![format!(
+                "{part}: ✖ panicked at {}",
+                self.error.as_deref().unwrap_or("an unknown location")
+            )],
+            (_, Status::TimedOut) => vec![format!("{part}: ✖ timed out{duration}")],
+            _ => vec![format!("{part}: ✖             ")],
+        };
+
+        if let Some(stats) = self.stats.filter(|stats| stats.samples > 1) {
+            lines.push(format!("        {ANSI_ITALIC}{stats}{ANSI_RESET}"));
+        }
+
+        lines.join("\n")
+    }
+
+    /// Whether the answer is correct, if the confirmed answer is known.
+    pub fn is_correct(&self) -> Option<bool> {
+        let expected = self.expected.as_ref()?;
+        Some(
+            self.status == Status::Solved
+                && self
+                    .answer
+                    .as_ref()
+                    .is_some_and(|answer| answer.matches(expected)),
+        )
+    }
+
+    fn format_check(&self) -> String {
+        match (self.is_correct(), &self.expected) {
+            (Some(true), _) => " ✓".into(),
+            (Some(false), Some(expected)) if !expected.is_multiline() => {
+                format!(" ✗ (expected {expected})")
+            }
+            (Some(false), _) => " ✗".into(),
+            (None, _) => String::new(),
+        }
+    }
+}
+
+/// A step of a solution that is shared by both parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// `parse` ran once and both parts took its result.
+    Parse,
+    /// `solve` returned the answers of both parts at once.
+    Solve,
+}
+
+impl Phase {
+    fn as_str(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Solve => "solve",
+        }
+    }
+
+    fn from_json(s: &str) -> Option<Self> {
+        match s {
+            "parse" => Some(Phase::Parse),
+            "solve" => Some(Phase::Solve),
+            _ => None,
+        }
+    }
+
+    /// The status of a phase that ran to completion.
+    fn done(self) -> &'static str {
+        match self {
+            Phase::Parse => "parsed",
+            Phase::Solve => Status::Solved.as_str(),
+        }
+    }
+}
+
+/// The outcome of a phase that is shared by both parts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhaseReport {
+    pub day: Day,
+    pub phase: Phase,
+    /// The label of the input, [`None`] for the input of the day.
+    pub input: Option<String>,
+    /// The location and message of a panic during the phase.
+    pub error: Option<String>,
+    pub stats: Stats,
+}
+
+impl PhaseReport {
+    pub fn is_failure(&self) -> bool {
+        self.error.is_some()
+    }
+
+    pub fn to_json(&self) -> Json {
+        let status = if self.is_failure() {
+            Status::Panicked.as_str()
+        } else {
+            self.phase.done()
+        };
+
+        Json::object([
+            ("day", self.day.into_inner().into()),
+            ("phase", self.phase.as_str().into()),
+            ("input", self.input.as_deref().into()),
+            ("status", status.into()),
+            ("error", self.error.as_deref().into()),
+            ("duration", self.stats.to_json()),
+        ])
+    }
+
+    pub fn from_json(value: &Json) -> Option<Self> {
+        let day = value.get("day")?.as_u64()?;
+        Some(Self {
+            day: Day::new(day.try_into().ok()?)?,
+            phase: Phase::from_json(value.get("phase")?.as_str()?)?,
+            input: value
+                .get("input")
+                .and_then(Json::as_str)
+                .map(ToString::to_string),
+            error: value
+                .get("error")
+                .and_then(Json::as_str)
+                .map(ToString::to_string),
+            stats: Stats::from_json(value.get("duration")?)?,
+        })
+    }
+
+    /// Parses a line of output of a solution binary. Returns [`None`] if the line is not a phase record.
+    pub fn parse_line(line: &str) -> Option<Self> {
+        if !line.starts_with('{') {
+            return None;
+        }
+        Self::from_json(&json::parse(line).ok()?)
+    }
+
+    pub fn to_text(&self) -> String {
+        let name = match self.phase {
+            Phase::Parse => "Parse",
+            Phase::Solve => "Solve",
+        };
+
+        let mut lines = match &self.error {
+            Some(error) => vec![format!("{name}: ✖ panicked at {error}")],
+            None => vec![format!("{name}:{}", format_duration(&self.stats))],
+        };
+
+        if self.stats.samples > 1 {
+            lines.push(format!("        {ANSI_ITALIC}{}{ANSI_RESET}", self.stats));
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// The records of one run of a day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayReport {
+    pub day: Day,
+    /// Only present for solutions that parse their input once or solve both parts at once.
+    pub phase: Option<PhaseReport>,
+    pub parts: Vec<PartReport>,
+}
+
+impl DayReport {
+    pub fn new(day: Day) -> Self {
+        Self {
+            day,
+            phase: None,
+            parts: vec![],
+        }
+    }
+
+    /// Whether the shared phase or a part failed.
+    pub fn is_failure(&self) -> bool {
+        self.phase.as_ref().is_some_and(PhaseReport::is_failure)
+            || self.parts.iter().any(|part| part.status.is_failure())
+    }
+}
+
+fn format_duration(stats: &Stats) -> String {
+    let duration = stats.headline();
+    if stats.samples == 1 {
+        format!(" ({duration:.1?})")
+    } else {
+        format!(" ({duration:.1?} @ {} samples)", stats.samples)
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{DayReport, PartReport, Phase, PhaseReport, Status};
+    use crate::template::bench::Stats;
+    use crate::{day, Part};
+    use std::time::Duration;
+
+    fn report(answer: Option<&str>) -> PartReport {
+        PartReport {
+            day: day!(1),
+            part: Part::Two,
+            input: None,
+            status: if answer.is_some() {
+                Status::Solved
+            } else {
+                Status::Unsolved
+            },
+            answer: answer.map(Into::into),
+            expected: Some("42".into()),
+            error: None,
+            stats: Some(Stats::from_samples(&[
+                Duration::from_nanos(70),
+                Duration::from_nanos(74),
+                Duration::from_nanos(80),
+            ])),
+        }
+    }
+
+    #[test]
+    fn round_trips_records() {
+        for report in [report(Some("42")), report(Some("a\nb")), report(None)] {
+            let line = report.to_json().to_string();
+            assert_eq!(PartReport::parse_line(&line), Some(report));
+        }
+    }
+
+    #[test]
+    fn keeps_answers_that_contain_timings() {
+        let line = report(Some("Part 1: 0 (74.13ns @ 100000 samples)"))
+            .to_json()
+            .to_string();
+        let parsed = PartReport::parse_line(&line).unwrap();
+        assert_eq!(
+            parsed.answer.unwrap(),
+            "Part 1: 0 (74.13ns @ 100000 samples)"
+        );
+        assert_eq!(parsed.stats.unwrap().median, Duration::from_nanos(74));
+    }
+
+    #[test]
+    fn checks_confirmed_answers() {
+        assert_eq!(report(Some("42")).is_correct(), Some(true));
+        assert!(report(Some("42")).to_text().contains(" ✓"));
+        assert!(report(Some("41")).to_text().contains(" ✗ (expected 42)"));
+        assert_eq!(report(None).is_correct(), Some(false));
+
+        let unknown = PartReport {
+            expected: None,
+            ..report(Some("42"))
+        };
+        assert_eq!(unknown.is_correct(), None);
+        assert!(!unknown.to_text().contains('✓'));
+    }
+
+    #[test]
+    fn reports_panics() {
+        let report = PartReport {
+            status: Status::Panicked,
+            error: Some("src/bin/01.rs:12:5: attempt to subtract with overflow".into()),
+            ..report(None)
+        };
+        assert!(report.status.is_failure());
+        assert_eq!(report.is_correct(), Some(false));
+        assert!(report
+            .to_text()
+            .contains("panicked at src/bin/01.rs:12:5: attempt to subtract with overflow"));
+
+        let line = report.to_json().to_string();
+        assert_eq!(PartReport::parse_line(&line), Some(report));
+    }
+
+    #[test]
+    fn reports_errors_with_their_sources() {
+        let report = PartReport {
+            status: Status::Failed,
+            error: Some("invalid grid\ncaused by: invalid digit found in string".into()),
+            ..report(None)
+        };
+        assert!(report.status.is_failure());
+        assert!(report
+            .to_text()
+            .starts_with("Part 2: ✖ failed: invalid grid\n        caused by: invalid digit"));
+
+        let line = report.to_json().to_string();
+        assert_eq!(PartReport::parse_line(&line), Some(report));
+    }
+
+    #[test]
+    fn reports_timeouts() {
+        let report = PartReport {
+            status: Status::TimedOut,
+            stats: Some(Stats::single(Duration::from_secs(10))),
+            ..report(None)
+        };
+        assert!(report.status.is_failure());
+        assert_eq!(report.to_text(), "Part 2: ✖ timed out (10.0s)");
+
+        let line = report.to_json().to_string();
+        assert!(line.contains("\"status\":\"timed_out\""));
+        assert_eq!(PartReport::parse_line(&line), Some(report));
+    }
+
+    #[test]
+    fn shows_drawn_letters() {
+        let art = "#..#.###\n#..#..#.\n####..#.\n#..#..#.\n#..#..#.\n#..#.###";
+        let report = PartReport {
+            expected: Some("HI".into()),
+            ..report(Some(art))
+        };
+        assert_eq!(report.is_correct(), Some(true));
+        assert!(report.to_text().contains("HI"));
+        assert!(report.to_text().contains(art));
+    }
+
+    #[test]
+    fn round_trips_phase_records() {
+        let parse = PhaseReport {
+            day: day!(1),
+            phase: Phase::Parse,
+            input: Some("alice".into()),
+            error: None,
+            stats: Stats::single(Duration::from_micros(12)),
+        };
+        let line = parse.to_json().to_string();
+        assert_eq!(PhaseReport::parse_line(&line), Some(parse.clone()));
+        assert_eq!(PartReport::parse_line(&line), None);
+        assert_eq!(parse.to_text(), "Parse: (12.0µs)");
+
+        let failed = PhaseReport {
+            phase: Phase::Solve,
+            error: Some("src/bin/01.rs:3:5: explicit panic".into()),
+            ..parse
+        };
+        let day = DayReport {
+            phase: Some(failed.clone()),
+            ..DayReport::new(day!(1))
+        };
+        assert!(day.is_failure());
+        assert!(failed.to_text().starts_with("Solve: ✖ panicked at"));
+        assert_eq!(
+            PhaseReport::parse_line(&failed.to_json().to_string()),
+            Some(failed)
+        );
+        assert_eq!(
+            PhaseReport::parse_line(&report(None).to_json().to_string()),
+            None
+        );
+    }
+
+    #[test]
+    fn omits_durations_of_parts_solved_together() {
+        let report = PartReport {
+            stats: None,
+            ..report(Some("42"))
+        };
+        assert_eq!(report.to_text(), "Part 2: \u{1b}[1m42\u{1b}[0m ✓");
+
+        let line = report.to_json().to_string();
+        assert!(line.contains("\"duration\":null"));
+        assert_eq!(PartReport::parse_line(&line), Some(report));
+    }
+
+    #[test]
+    fn ignores_other_output() {
+        assert_eq!(PartReport::parse_line("Part 1: 42 (1.0ms)"), None);
+        assert_eq!(PartReport::parse_line("{\"debug\": true}"), None);
+    }
+}
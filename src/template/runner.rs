@@ -1,65 +1,667 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
-use crate::Day;
+use crate::template::bench::{BenchOptions, Stats};
+use crate::template::input;
+use crate::template::params;
+use crate::template::puzzle;
+use crate::template::registry::{PartOutput, PartResult, Solution};
+use crate::template::report::{DayReport, Format, PartReport, Phase, PhaseReport, Status};
+use crate::template::submissions::{Ledger, Verdict};
+use crate::template::timeout::Timeouts;
+use crate::template::{aoc_client, ANSI_ITALIC, ANSI_RESET};
+use crate::{Answer, Day, Part, Year};
+use std::cell::{Cell, RefCell};
 use std::fmt::Display;
-use std::io::{stdout, Write};
-use std::process::Output;
+use std::io::{self, stdout, BufRead, BufReader, Read, Write};
+use std::panic::{self, AssertUnwindSafe, PanicHookInfo};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{env, process, thread};
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+/// What a solution runs: its day, the parts selected with `--part` and the input passed with `--input`.
+pub struct RunOptions {
+    pub year: Year,
+    pub day: Day,
+    pub parts: Vec<Part>,
+    /// The label of the input, [`None`] for the input of the day.
+    /// Answers of other inputs are neither checked against confirmed answers nor submitted.
+    pub input: Option<String>,
+    /// Parameters were passed with `--param`. Like answers of other inputs, the answers are neither
+    /// checked against confirmed answers nor submitted.
+    pub has_params: bool,
+}
+
+/// Entry point of a solution binary. Exits with an error if no input is usable or a part failed.
+pub fn run_main(solution: &Solution) {
+    let reports = run_source(solution);
+
+    if reports.is_empty() || reports.iter().any(DayReport::is_failure) {
+        process::exit(1);
+    }
+}
+
+/// Runs a registered solution against the inputs passed with `--input`, or against the input of its day.
+pub fn run_source(solution: &Solution) -> Vec<DayReport> {
+    let args: Vec<String> = env::args().collect();
+
+    if args.iter().any(|x| x == CHILD_FLAG) {
+        return run_child(solution).into_iter().collect();
+    }
+
+    match input::Source::from_args(&args) {
+        input::Source::Day => run_solution(solution).into_iter().collect(),
+        source => run_labelled(solution, &source),
+    }
+}
+
+/// Runs both parts of a registered solution against the puzzle input of its day, or only the part passed with `--part`.
+/// Returns [`None`] if the input is missing or unusable, see [`input::check`].
+pub fn run_solution(solution: &Solution) -> Option<DayReport> {
+    let options = run_options(solution, None);
+    let input = input::load(options.year, options.day)?;
+    Some(run_input(solution, &input, &options))
+}
+
+/// Runs a registered solution against each input of `--input` in turn. In text mode, the results of
+/// each input follow a line with its label.
+fn run_labelled(solution: &Solution, source: &input::Source) -> Vec<DayReport> {
+    let args: Vec<String> = env::args().collect();
+    let format = Format::from_args(&args);
+
+    input::load_labelled(source)
+        .into_iter()
+        .enumerate()
+        .map(|(i, input)| {
+            if format == Format::Text {
+                if i > 0 {
+                    println!();
+                }
+                println!("{ANSI_BOLD}Input {}{ANSI_RESET}", input.label);
+            }
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+            let options = run_options(solution, Some(input.label));
+            run_input(solution, &input.text, &options)
+        })
+        .collect()
+}
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+/// Runs a solution against one input, in child processes if a timeout was passed, see [`run_watched`].
+fn run_input(solution: &Solution, input: &str, options: &RunOptions) -> DayReport {
+    let args: Vec<String> = env::args().collect();
+    let timeouts = Timeouts::from_args(&args);
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    if timeouts.is_set() {
+        run_watched(input, options, timeouts)
+    } else {
+        (solution.run)(input, options)
     }
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Clone, T>(
+fn run_options(solution: &Solution, input: Option<String>) -> RunOptions {
+    let Some(year) = solution.year() else {
+        eprintln!("no year configured. Set AOC_YEAR in \".cargo/config.toml\".");
+        process::exit(1);
+    };
+
+    let args: Vec<String> = env::args().collect();
+    let parts = match part_from_args(&args, "--part") {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    RunOptions {
+        year,
+        day: solution.day,
+        parts,
+        input,
+        has_params: params::is_set(),
+    }
+}
+
+/// Runs the selected parts of a solution whose parts take the input.
+pub fn run_parts<R1: PartOutput, R2: PartOutput>(
+    input: &str,
+    options: &RunOptions,
+    part_one: impl Fn(&str) -> R1,
+    part_two: impl Fn(&str) -> R2,
+) -> DayReport {
+    DayReport {
+        parts: run_selected_parts(
+            input,
+            options,
+            |input| part_one(input).into_part_result(),
+            |input| part_two(input).into_part_result(),
+        ),
+        ..DayReport::new(options.day)
+    }
+}
+
+/// Runs `parse` once and the selected parts on its result. Parsing is timed and reported on its own.
+pub fn run_parsed<'a, P, R1: PartOutput, R2: PartOutput>(
+    input: &'a str,
+    options: &RunOptions,
+    parse: impl Fn(&'a str) -> P,
+    part_one: impl Fn(&P) -> R1,
+    part_two: impl Fn(&P) -> R2,
+) -> DayReport {
+    let (parsed, parse_report) = run_phase(Phase::Parse, parse, input, options);
+
+    let parts = match &parsed {
+        Some(parsed) => run_selected_parts(
+            parsed,
+            options,
+            |parsed| part_one(parsed).into_part_result(),
+            |parsed| part_two(parsed).into_part_result(),
+        ),
+        None => vec![],
+    };
+
+    DayReport {
+        day: options.day,
+        phase: Some(parse_report),
+        parts,
+    }
+}
+
+/// Runs `solve`, which returns the answers of both parts at once, and reports the selected parts.
+/// Both parts are timed as one unit, so only the solve phase has a duration.
+pub fn run_combined<R1: PartOutput, R2: PartOutput>(
+    input: &str,
+    options: &RunOptions,
+    solve: impl Fn(&str) -> (R1, R2),
+) -> DayReport {
+    let (answers, solve_report) = run_phase(Phase::Solve, solve, input, options);
+
+    let parts = match answers {
+        Some((part_one, part_two)) => {
+            let (part_one, part_two) = (part_one.into_part_result(), part_two.into_part_result());
+            options
+                .parts
+                .iter()
+                .map(|&part| {
+                    let result = match part {
+                        Part::One => part_one.clone(),
+                        Part::Two => part_two.clone(),
+                    };
+                    let result = result.map_err(Failure::Failed);
+                    report_part(result, None, options, part)
+                })
+                .collect()
+        }
+        None => vec![],
+    };
+
+    DayReport {
+        day: options.day,
+        phase: Some(solve_report),
+        parts,
+    }
+}
+
+fn run_selected_parts<I: Clone>(
+    input: I,
+    options: &RunOptions,
+    part_one: impl Fn(I) -> PartResult,
+    part_two: impl Fn(I) -> PartResult,
+) -> Vec<PartReport> {
+    options
+        .parts
+        .iter()
+        .map(|&part| {
+            let input = input.clone();
+            match part {
+                Part::One => run_part(&part_one, input, options, part),
+                Part::Two => run_part(&part_two, input, options, part),
+            }
+        })
+        .collect()
+}
+
+/// Runs and prints a phase that is shared by both parts. Returns [`None`] instead of its result if it panicked.
+fn run_phase<I: Clone, T>(
+    phase: Phase,
     func: impl Fn(I) -> T,
     input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    options: &RunOptions,
+) -> (Option<T>, PhaseReport) {
+    let args: Vec<String> = env::args().collect();
+    let bench_options = BenchOptions::from_args(&args);
+    let format = Format::from_args(&args);
+
+    let (result, stats) = run_guarded(
+        |input| Ok(func(input)),
+        input,
+        bench_options.as_ref(),
+        |_| {
+            if format == Format::Text && bench_options.is_some() {
+                let name = match phase {
+                    Phase::Parse => "Parse",
+                    Phase::Solve => "Solve",
+                };
+                print!("{name}: > {ANSI_ITALIC}benching{ANSI_RESET}");
+                let _ = stdout().flush();
+            }
+        },
+    );
+
+    let (value, error) = match result {
+        Ok(value) => (Some(value), None),
+        Err(Failure::Panicked(error) | Failure::Failed(error)) => (None, Some(error)),
+    };
+
+    let report = PhaseReport {
+        day: options.day,
+        phase,
+        input: options.input.clone(),
+        error,
+        stats,
+    };
+
+    print_phase(&report, format);
+    (value, report)
+}
+
+fn print_phase(report: &PhaseReport, format: Format) {
+    match format {
+        Format::Text => {
+            print!("\r");
+            println!("{}", report.to_text());
+        }
+        Format::Json => println!("{}", report.to_json()),
+    }
+}
+
+/// Why a part did not return.
+enum Failure {
+    Panicked(String),
+    Failed(String),
+}
+
+pub fn run_part<I: Clone>(
+    func: impl Fn(I) -> PartResult,
+    input: I,
+    options: &RunOptions,
+    part: Part,
+) -> PartReport {
+    let part_str = format!("Part {part}");
+    let args: Vec<String> = env::args().collect();
+    let bench_options = BenchOptions::from_args(&args);
+    let format = Format::from_args(&args);
+
+    let func = |input: I| func(input).map_err(Failure::Failed);
+
+    let (result, stats) = run_guarded(func, input, bench_options.as_ref(), |result| {
+        if format == Format::Text {
+            let answer = result.as_ref().ok().and_then(Option::as_ref);
+            print_intermediate_result(answer, &part_str, bench_options.is_some());
+        }
+    });
+
+    report_part(result, Some(stats), options, part)
+}
+
+/// Prints the report of a part and submits its answer if the part was passed with `--submit`.
+fn report_part(
+    result: Result<Option<Answer>, Failure>,
+    stats: Option<Stats>,
+    options: &RunOptions,
+    part: Part,
+) -> PartReport {
+    let args: Vec<String> = env::args().collect();
+    let format = Format::from_args(&args);
+    let submit_part = part_from_args(&args, "--submit");
+
+    let (status, answer, error) = match result {
+        Ok(Some(answer)) => (Status::Solved, Some(answer), None),
+        Ok(None) => (Status::Unsolved, None, None),
+        Err(Failure::Panicked(error)) => (Status::Panicked, None, Some(error)),
+        Err(Failure::Failed(error)) => (Status::Failed, None, Some(error)),
+    };
+
+    let (year, day) = (options.year, options.day);
+    // confirmed answers belong to the input of the day, solved without parameters.
+    let expected = match (&options.input, options.has_params) {
+        (None, false) => puzzle::confirmed_answers(year, day).get(part).cloned(),
+        _ => None,
+    };
+
+    let report = PartReport {
+        day,
+        part,
+        input: options.input.clone(),
+        status,
+        answer,
+        expected,
+        error,
+        stats,
+    };
+
+    match format {
+        Format::Text => {
+            print!("\r");
+            println!("{}", report.to_text());
+        }
+        Format::Json => println!("{}", report.to_json()),
+    }
+
+    if let Some(answer) = report.answer.as_ref().filter(|_| submit_part == Some(part)) {
+        if let Some(label) = &options.input {
+            eprintln!("Refusing to submit: the answer belongs to input {label}, not to the input of the day.");
+            return report;
+        }
+
+        if options.has_params {
+            eprintln!("Refusing to submit: the answer was computed with parameters.");
+            return report;
+        }
+
+        // answers drawn with `#` are submitted as the letters they draw.
+        let answer = match answer.letters() {
+            Some(letters) => letters,
+            None if answer.is_multiline() => {
+                eprintln!("Refusing to submit: the multi-line answer does not draw known letters.");
+                return report;
+            }
+            None => answer.clone(),
+        };
+
+        if let Some(Err(e)) = submit_result(&answer, year, day, part) {
+            eprintln!("failed to submit result: {e}");
+        }
+    }
+
+    report
+}
+
+/// Runs a function like [`run_timed`] and turns a panic into [`Failure::Panicked`], whether it happens
+/// in the function or while it is benched.
+fn run_guarded<I: Clone, T>(
+    func: impl Fn(I) -> Result<T, Failure>,
+    input: I,
+    bench_options: Option<&BenchOptions>,
+    hook: impl Fn(&Result<T, Failure>),
+) -> (Result<T, Failure>, Stats) {
+    let is_hooked = Cell::new(false);
+    let hook = |result: &Result<T, Failure>| {
+        is_hooked.set(true);
+        hook(result);
+    };
+
+    let timer = Instant::now();
+    match catch_panic(|| run_timed(&func, input, bench_options, hook)) {
+        Ok(timed) => timed,
+        Err(error) => {
+            let result = Err(Failure::Panicked(error));
+            if !is_hooked.get() {
+                hook(&result);
+            }
+            (result, Stats::single(timer.elapsed()))
+        }
+    }
+}
+
+/// Internal flag of a child process that runs a solution for [`run_watched`]. The child reads its input,
+/// already checked by the parent, from stdin.
+const CHILD_FLAG: &str = "--watched-child";
+
+/// Runs the part passed with `--part`, or both parts, against the input on stdin. Entry point of a child
+/// spawned by [`run_watched`], which passes `--format json`.
+fn run_child(solution: &Solution) -> Option<DayReport> {
+    let mut input = String::new();
+    if let Err(e) = io::stdin().read_to_string(&mut input) {
+        eprintln!("Failed to read the input from stdin: {e}");
+        return None;
+    }
+
+    let options = run_options(solution, None);
+    Some((solution.run)(&input, &options))
+}
+
+/// Runs a solution against `input` in a child process of the current binary, so that a step that runs past
+/// a timeout is killed instead of running on in the background. The child prints a record for each step,
+/// which is reported here as if the step ran in this process, so answers are checked and submitted here.
+///
+/// When a step times out, the child is killed and the parts that are left run in a new child.
+/// Their part timeout includes parsing the input again, which is not reported twice.
+fn run_watched(input: &str, options: &RunOptions, timeouts: Timeouts) -> DayReport {
+    let format = Format::from_args(&env::args().collect::<Vec<String>>());
+    let day_deadline = timeouts.day.map(|day| Instant::now() + day);
+
+    let mut report = DayReport::new(options.day);
+    let mut remaining = options.parts.clone();
+
+    while !remaining.is_empty() {
+        let (mut child, lines) = match spawn_child(input, options, &remaining) {
+            Ok(child) => child,
+            Err(e) => {
+                let error = format!("failed to run the solution in a child process: {e}");
+                report.parts.extend(remaining.drain(..).map(|part| {
+                    report_part(Err(Failure::Failed(error.clone())), None, options, part)
+                }));
+                break;
+            }
+        };
+
+        let mut step_started = Instant::now();
+
+        loop {
+            let deadline = [timeouts.part.map(|part| step_started + part), day_deadline]
+                .into_iter()
+                .flatten()
+                .min();
+            let line = match deadline {
+                Some(deadline) => {
+                    lines.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                }
+                None => lines.recv().map_err(|_| RecvTimeoutError::Disconnected),
+            };
+
+            match line {
+                Ok(line) => {
+                    if let Some(part) = PartReport::parse_line(&line) {
+                        remaining.retain(|&x| x != part.part);
+                        let result = reported_result(&part);
+                        report
+                            .parts
+                            .push(report_part(result, part.stats, options, part.part));
+                    } else if let Some(phase) = PhaseReport::parse_line(&line) {
+                        // parts do not run after the shared phase failed.
+                        if phase.is_failure() {
+                            remaining.clear();
+                        }
+
+                        if report.phase.is_some() {
+                            continue;
+                        }
+
+                        let phase = PhaseReport {
+                            input: options.input.clone(),
+                            ..phase
+                        };
+                        print_phase(&phase, format);
+                        report.phase = Some(phase);
+                    } else {
+                        // output of the solution itself.
+                        println!("{line}");
+                        continue;
+                    }
+
+                    step_started = Instant::now();
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    let elapsed = step_started.elapsed();
+                    // the child might have exited in the meantime, which `wait` picks up.
+                    let _ = child.kill();
+                    let _ = child.wait();
+
+                    // past the day timeout, none of the parts that are left can run.
+                    let timed_out = if day_deadline.is_some_and(|day| Instant::now() >= day) {
+                        remaining.split_off(0)
+                    } else {
+                        vec![remaining.remove(0)]
+                    };
+
+                    report.parts.extend(
+                        timed_out
+                            .into_iter()
+                            .map(|part| report_timed_out(options, part, elapsed, format)),
+                    );
+                    break;
+                }
+                Err(RecvTimeoutError::Disconnected) => {
+                    // a child that exits before reporting its parts crashed, e.g. with a stack overflow.
+                    let error = match child.wait() {
+                        Ok(status) => format!("the solution stopped with {status}"),
+                        Err(e) => format!("the solution stopped: {e}"),
+                    };
+                    report.parts.extend(remaining.drain(..).map(|part| {
+                        report_part(Err(Failure::Panicked(error.clone())), None, options, part)
+                    }));
+                    break;
+                }
+            }
+        }
+    }
+
+    report
+}
+
+/// Spawns a child of the current binary that runs `parts` against `input`, see [`run_child`].
+/// Returns the child and the lines it prints.
+fn spawn_child(
+    input: &str,
+    options: &RunOptions,
+    parts: &[Part],
+) -> io::Result<(Child, Receiver<String>)> {
+    let args: Vec<String> = env::args().collect();
+
+    // `--year` and `--day` select the solution in the `all` binary.
+    let mut child_args = vec![
+        CHILD_FLAG.into(),
+        "--year".into(),
+        options.year.to_string(),
+        "--day".into(),
+        options.day.to_string(),
+        "--format".into(),
+        "json".into(),
+    ];
+
+    // without `--part`, the child runs both parts.
+    if let [part] = parts {
+        child_args.push("--part".into());
+        child_args.push(part.to_string());
+    }
+
+    if let Some(bench_options) = BenchOptions::from_args(&args) {
+        child_args.extend(bench_options.to_args());
+    }
+
+    child_args.extend(params::to_args());
+
+    let mut child = Command::new(env::current_exe()?)
+        .args(&child_args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // a child that exits before reading its input is reported once its output ends.
+        let _ = stdin.write_all(input.as_bytes());
+    }
+
+    let (sender, receiver) = mpsc::channel();
+    let stdout = child.stdout.take().map(BufReader::new);
+    thread::spawn(move || {
+        for line in stdout
+            .into_iter()
+            .flat_map(BufRead::lines)
+            .map_while(Result::ok)
+        {
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    Ok((child, receiver))
+}
+
+/// The result of a part that ran in a child process, read back from its record.
+fn reported_result(report: &PartReport) -> Result<Option<Answer>, Failure> {
+    let error = report.error.clone().unwrap_or_default();
+
+    match report.status {
+        Status::Solved | Status::Unsolved => Ok(report.answer.clone()),
+        Status::Panicked => Err(Failure::Panicked(error)),
+        Status::Failed | Status::TimedOut => Err(Failure::Failed(error)),
+    }
+}
+
+/// Prints and returns the report of a part that was killed at its timeout.
+fn report_timed_out(
+    options: &RunOptions,
+    part: Part,
+    elapsed: Duration,
+    format: Format,
+) -> PartReport {
+    let report = PartReport {
+        day: options.day,
+        part,
+        input: options.input.clone(),
+        status: Status::TimedOut,
+        answer: None,
+        expected: None,
+        error: None,
+        stats: Some(Stats::single(elapsed)),
+    };
+
+    match format {
+        Format::Text => println!("{}", report.to_text()),
+        Format::Json => println!("{}", report.to_json()),
+    }
+
+    report
+}
+
+/// Run a solution part. The behavior differs depending on whether the `--time` flag is set:
+///  1. without it, the function is executed once.
+///  2. with it, the function is benched after a warmup (by default approx. 1 second of execution time or 10 samples, whatever takes longer.)
+fn run_timed<I: Clone, T, E>(
+    func: impl Fn(I) -> Result<T, E>,
+    input: I,
+    bench_options: Option<&BenchOptions>,
+    hook: impl Fn(&Result<T, E>),
+) -> (Result<T, E>, Stats) {
     let timer = Instant::now();
     let result = func(input.clone());
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
+    let stats = match bench_options {
+        // a part that failed is not benched.
+        Some(options) if result.is_ok() => bench(func, input, base_time, options),
+        _ => Stats::single(base_time),
     };
 
-    (result, run.0, run.1)
+    (result, stats)
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
-
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
-
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: Duration,
+    options: &BenchOptions,
+) -> Stats {
+    for _ in 0..options.warmup_count(base_time) {
+        func(input.clone());
+    }
 
-    let mut timers: Vec<Duration> = vec![];
+    let bench_iterations = options.sample_count(base_time);
+    let mut timers: Vec<Duration> = Vec::with_capacity(bench_iterations as usize);
 
     for _ in 0..bench_iterations {
         // need a clone here to make the borrow checker happy.
@@ -69,99 +671,239 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    Stats::from_samples(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
-}
+thread_local! {
+    /// Location and message of the last panic on this thread, recorded by [`record_panic`].
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
-    }
+    /// Set while [`catch_panic`] runs a function on this thread.
+    static IS_CATCHING: Cell<bool> = const { Cell::new(false) };
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
-    let is_intermediate_result = duration_str.is_empty();
+type PanicHook = Box<dyn Fn(&PanicHookInfo) + Sync + Send + 'static>;
 
-    match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
-                }
+/// Number of running [`catch_panic`] calls and the hook that was installed before the first of them.
+static PREVIOUS_HOOK: Mutex<(usize, Option<Arc<PanicHook>>)> = Mutex::new((0, None));
+
+/// Replaces the panic hook with one that records panics of threads inside [`catch_panic`]
+/// and passes panics of other threads on to the previous hook.
+fn install_panic_hook() {
+    let mut previous_hook = PREVIOUS_HOOK.lock().unwrap();
+
+    if previous_hook.0 == 0 {
+        let previous = Arc::new(panic::take_hook());
+        previous_hook.1 = Some(Arc::clone(&previous));
+        panic::set_hook(Box::new(move |info| {
+            if IS_CATCHING.with(Cell::get) {
+                record_panic(info);
             } else {
-                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                }
+                previous(info);
             }
+        }));
+    }
+
+    previous_hook.0 += 1;
+}
+
+/// Restores the previous panic hook once the last running [`catch_panic`] returns.
+fn restore_panic_hook() {
+    let mut previous_hook = PREVIOUS_HOOK.lock().unwrap();
+    previous_hook.0 -= 1;
+
+    if previous_hook.0 == 0 {
+        // dropping the installed hook releases its reference to the previous one.
+        drop(panic::take_hook());
+        if let Some(previous) = previous_hook.1.take().and_then(|x| Arc::try_unwrap(x).ok()) {
+            panic::set_hook(previous);
         }
-        None => {
-            if is_intermediate_result {
-                print!("{part}: ✖");
-            } else {
-                print!("\r");
-                println!("{part}: ✖             ");
-            }
+    }
+}
+
+/// Panic hook that records the panic instead of printing it, so that the report of the part can show it.
+fn record_panic(info: &PanicHookInfo) {
+    let payload = info.payload();
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("Box<dyn Any>");
+
+    let error = match info.location() {
+        Some(location) => format!("{location}: {message}"),
+        None => message.to_string(),
+    };
+
+    LAST_PANIC.with(|last| *last.borrow_mut() = Some(error));
+}
+
+/// Runs a part and turns a panic into an error with its location and message.
+/// The panic hook is replaced only while the part runs, so the panic is reported instead of printed.
+fn catch_panic<T>(func: impl FnOnce() -> T) -> Result<T, String> {
+    install_panic_hook();
+    let was_catching = IS_CATCHING.with(|x| x.replace(true));
+
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+
+    IS_CATCHING.with(|x| x.set(was_catching));
+    restore_panic_hook();
+
+    result.map_err(|_| {
+        LAST_PANIC
+            .with(|last| last.borrow_mut().take())
+            .unwrap_or_else(|| "an unknown location".into())
+    })
+}
+
+/// Prints the result of the first execution while the part is benched.
+/// The line is overwritten by the final report.
+fn print_intermediate_result<T: Display>(result: Option<&T>, part: &str, is_benched: bool) {
+    match result {
+        Some(result) if result.to_string().contains('\n') => print!("{part}: ▼"),
+        Some(result) => print!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}"),
+        None => print!("{part}: ✖"),
+    }
+
+    if is_benched {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+    }
+
+    let _ = stdout().flush();
+}
+
+/// Reads `--submit <part>` or `--part <part>` from the arguments passed to `solve`.
+fn part_from_args(args: &[String], name: &str) -> Option<Part> {
+    let index = args.iter().position(|x| x == name)?;
+
+    match args.get(index + 1).map(|x| x.parse::<Part>()) {
+        Some(Ok(part)) => Some(part),
+        _ => {
+            eprintln!("Unexpected command-line input. Format: cargo solve 1 {name} 1");
+            process::exit(1);
         }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
+/// Try to submit one part of the solution if:
+///  1. an advent of code client is available.
+///  2. the submission ledger does not already rule out the answer.
+fn submit_result(
+    answer: &Answer,
+    year: Year,
     day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+    part: Part,
+) -> Option<Result<Verdict, aoc_client::AocClientError>> {
+    let client = match aoc_client::client() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("failed to set up advent of code client: {e}");
+            process::exit(1);
+        }
+    };
 
-    if !args.contains(&"--submit".into()) {
+    let mut ledger = match Ledger::load(year, day) {
+        Ok(ledger) => ledger,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    if let Err(rejection) = ledger.check(part, answer) {
+        eprintln!("Refusing to submit {answer}: {rejection}");
         return None;
     }
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    println!("Submitting result...");
+    let verdict = match client.submit(year, day, part, answer) {
+        Ok(message) => Verdict::parse(&message),
+        Err(e) => return Some(Err(e)),
+    };
+
+    println!("{ANSI_BOLD}Verdict:{ANSI_RESET} {verdict}");
+    ledger.record(part, answer, verdict);
+    if let Err(e) = ledger.save(year, day) {
+        eprintln!("{e}");
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    Some(Ok(verdict))
+}
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
-    };
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{catch_panic, reported_result, run_guarded, Failure};
+    use crate::template::bench::BenchOptions;
+    use crate::template::report::{PartReport, Status};
+    use crate::{Answer, Part};
+    use std::cell::Cell;
+    use std::time::Duration;
 
-    if part_submit != part {
-        return None;
+    #[test]
+    fn catches_panics_with_location_and_message() {
+        assert_eq!(catch_panic(|| 42), Ok(42));
+
+        let error = catch_panic(|| panic!("no start position")).unwrap_err();
+        assert!(error.contains("runner.rs"), "{error}");
+        assert!(error.ends_with(": no start position"), "{error}");
+
+        let error = catch_panic(|| std::panic::panic_any(7)).unwrap_err();
+        assert!(error.ends_with(": Box<dyn Any>"), "{error}");
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    #[test]
+    fn reports_panics_while_benching() {
+        let calls = Cell::new(0);
+        let options = BenchOptions {
+            budget: Duration::from_millis(1),
+            samples: Some(5),
+        };
+
+        let (result, _) = run_guarded(
+            |()| {
+                calls.set(calls.get() + 1);
+                assert!(calls.get() < 3, "only works twice");
+                Ok(())
+            },
+            (),
+            Some(&options),
+            |_| {},
+        );
+
+        match result {
+            Err(Failure::Panicked(error)) => assert!(error.ends_with("only works twice")),
+            _ => panic!("expected a panic while benching"),
+        }
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    #[test]
+    fn reads_results_back_from_child_records() {
+        let record = |status, answer: Option<Answer>, error: Option<&str>| PartReport {
+            day: crate::day!(1),
+            part: Part::One,
+            input: Some("stdin".into()),
+            status,
+            answer,
+            expected: None,
+            error: error.map(String::from),
+            stats: None,
+        };
+
+        match reported_result(&record(Status::Solved, Some(42_u64.into()), None)) {
+            Ok(Some(answer)) => assert_eq!(answer, Answer::from(42_u64)),
+            _ => panic!("expected an answer"),
+        }
+        assert!(matches!(
+            reported_result(&record(Status::Unsolved, None, None)),
+            Ok(None)
+        ));
+        assert!(matches!(
+            reported_result(&record(Status::Panicked, None, Some("boom"))),
+            Err(Failure::Panicked(error)) if error == "boom"
+        ));
+        assert!(matches!(
+            reported_result(&record(Status::Failed, None, Some("no path"))),
+            Err(Failure::Failed(error)) if error == "no path"
+        ));
+    }
 }
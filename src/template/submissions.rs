@@ -0,0 +1,411 @@
+/// Module that keeps a ledger of submitted answers and their verdicts in `data/submissions/{day}.json` (`data/{year}/submissions` for other years).
+/// The ledger is used to refuse submissions that are already known to be wrong.
+use std::{
+    fmt::Display,
+    fs, io,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use regex::Regex;
+
+use crate::template::json::{self, Json};
+use crate::template::paths;
+use crate::{Answer, Day, Part, Year};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse submission ledger: {e}"),
+            Error::IO(e) => write!(f, "could not access submission ledger: {e}"),
+        }
+    }
+}
+
+/// The response of the website to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited { wait_secs: u64 },
+    AlreadyCompleted,
+    Unknown,
+}
+
+impl Verdict {
+    /// Parses the verdict from the response message of the website.
+    pub fn parse(message: &str) -> Self {
+        if message.contains("That's the right answer") {
+            Verdict::Correct
+        } else if message.contains("That's not the right answer") {
+            if message.contains("too high") {
+                Verdict::TooHigh
+            } else if message.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if message.contains("You gave an answer too recently") {
+            let re = Regex::new(r"(?:(\d+)m )?(\d+)s left to wait").unwrap();
+            let wait_secs = re.captures(message).map_or(0, |c| {
+                let minutes: u64 = c.get(1).map_or(0, |m| m.as_str().parse().unwrap_or(0));
+                let seconds: u64 = c[2].parse().unwrap_or(0);
+                minutes * 60 + seconds
+            });
+            Verdict::RateLimited { wait_secs }
+        } else if message.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadyCompleted
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Wrong => "wrong",
+            Verdict::RateLimited { .. } => "rate_limited",
+            Verdict::AlreadyCompleted => "already_completed",
+            Verdict::Unknown => "unknown",
+        }
+    }
+
+    fn from_json(verdict: &str, wait_secs: Option<u64>) -> Option<Self> {
+        Some(match verdict {
+            "correct" => Verdict::Correct,
+            "too_high" => Verdict::TooHigh,
+            "too_low" => Verdict::TooLow,
+            "wrong" => Verdict::Wrong,
+            "rate_limited" => Verdict::RateLimited {
+                wait_secs: wait_secs.unwrap_or(0),
+            },
+            "already_completed" => Verdict::AlreadyCompleted,
+            "unknown" => Verdict::Unknown,
+            _ => return None,
+        })
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited { wait_secs } => write!(f, "rate-limited ({wait_secs}s to wait)"),
+            Verdict::AlreadyCompleted => write!(f, "already completed"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub part: Part,
+    pub answer: Answer,
+    pub timestamp: u64,
+    pub verdict: Verdict,
+}
+
+impl Submission {
+    fn to_json(&self) -> Json {
+        let wait_secs = match self.verdict {
+            Verdict::RateLimited { wait_secs } => Some(wait_secs),
+            _ => None,
+        };
+        Json::object([
+            ("part", self.part.into_inner().into()),
+            ("answer", self.answer.to_json()),
+            ("timestamp", self.timestamp.into()),
+            ("verdict", self.verdict.as_str().into()),
+            ("wait_secs", wait_secs.into()),
+        ])
+    }
+
+    fn from_json(value: &Json) -> Option<Self> {
+        let wait_secs = value.get("wait_secs").and_then(Json::as_u64);
+        Some(Self {
+            part: Part::new(value.get("part")?.as_u64()?.try_into().ok()?)?,
+            answer: Answer::from_json(value.get("answer")?)?,
+            timestamp: value.get("timestamp")?.as_u64()?,
+            verdict: Verdict::from_json(value.get("verdict")?.as_str()?, wait_secs)?,
+        })
+    }
+}
+
+/// Reasons for refusing to submit an answer.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Rejection {
+    AlreadySolved(Answer),
+    KnownWrong(Verdict),
+    AboveTooHigh(Answer),
+    BelowTooLow(Answer),
+    RateLimited { wait_secs: u64 },
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rejection::AlreadySolved(answer) => {
+                write!(f, "this part was already solved with answer {answer}.")
+            }
+            Rejection::KnownWrong(verdict) => {
+                write!(f, "this answer was already submitted and was {verdict}.")
+            }
+            Rejection::AboveTooHigh(bound) => {
+                write!(f, "this answer is not below {bound}, which was too high.")
+            }
+            Rejection::BelowTooLow(bound) => {
+                write!(f, "this answer is not above {bound}, which was too low.")
+            }
+            Rejection::RateLimited { wait_secs } => {
+                write!(
+                    f,
+                    "rate-limited, wait another {wait_secs}s before submitting."
+                )
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Ledger {
+    pub submissions: Vec<Submission>,
+}
+
+impl Ledger {
+    /// Loads the ledger of a day. Returns an empty ledger if nothing was submitted yet.
+    pub fn load(year: Year, day: Day) -> Result<Self, Error> {
+        let path = get_path(year, day);
+        if !Path::new(&path).exists() {
+            return Ok(Self::default());
+        }
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn save(&self, year: Year, day: Day) -> Result<(), Error> {
+        fs::create_dir_all(format!("{}/submissions", paths::data_dir(year)))?;
+        fs::write(get_path(year, day), self.serialize())?;
+        Ok(())
+    }
+
+    fn parse(s: &str) -> Result<Self, Error> {
+        let value = json::parse(s).map_err(|e| Error::Parser(e.to_string()))?;
+        let submissions = value
+            .as_array()
+            .ok_or_else(|| Error::Parser("expected an array of submissions.".into()))?
+            .iter()
+            .map(|item| {
+                Submission::from_json(item)
+                    .ok_or_else(|| Error::Parser(format!("malformed submission: {item}")))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { submissions })
+    }
+
+    fn serialize(&self) -> String {
+        let lines: Vec<String> = self
+            .submissions
+            .iter()
+            .map(|s| format!("  {}", s.to_json()))
+            .collect();
+
+        if lines.is_empty() {
+            "[]\n".into()
+        } else {
+            format!("[\n{}\n]\n", lines.join(",\n"))
+        }
+    }
+
+    /// Appends a submission with the current timestamp.
+    pub fn record(&mut self, part: Part, answer: &Answer, verdict: Verdict) {
+        self.submissions.push(Submission {
+            part,
+            answer: answer.clone(),
+            timestamp: now(),
+            verdict,
+        });
+    }
+
+    /// Checks whether the ledger already rules out an answer.
+    pub fn check(&self, part: Part, answer: &Answer) -> Result<(), Rejection> {
+        self.check_at(part, answer, now())
+    }
+
+    fn check_at(&self, part: Part, answer: &Answer, now: u64) -> Result<(), Rejection> {
+        let submissions = self.submissions.iter().filter(|s| s.part == part);
+
+        if let Some(last) = self.submissions.last() {
+            if let Verdict::RateLimited { wait_secs } = last.verdict {
+                let until = last.timestamp + wait_secs;
+                if until > now {
+                    return Err(Rejection::RateLimited {
+                        wait_secs: until - now,
+                    });
+                }
+            }
+        }
+
+        let answer_value = answer.as_integer();
+
+        for submission in submissions {
+            match submission.verdict {
+                Verdict::Correct => {
+                    return Err(Rejection::AlreadySolved(submission.answer.clone()));
+                }
+                verdict @ (Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+                    if &submission.answer == answer =>
+                {
+                    return Err(Rejection::KnownWrong(verdict));
+                }
+                _ => {}
+            }
+
+            let (Some(value), Some(bound)) = (answer_value, submission.answer.as_integer()) else {
+                continue;
+            };
+
+            match submission.verdict {
+                Verdict::TooHigh if value >= bound => {
+                    return Err(Rejection::AboveTooHigh(submission.answer.clone()));
+                }
+                Verdict::TooLow if value <= bound => {
+                    return Err(Rejection::BelowTooLow(submission.answer.clone()));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn get_path(year: Year, day: Day) -> String {
+    paths::data_file(year, "submissions", day, "json")
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Ledger, Rejection, Submission, Verdict};
+    use crate::Part;
+
+    fn submission(part: u8, answer: &str, timestamp: u64, verdict: Verdict) -> Submission {
+        Submission {
+            part: Part::new(part).unwrap(),
+            answer: answer.into(),
+            timestamp,
+            verdict,
+        }
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(
+            Verdict::parse("That's the right answer! You are one gold star closer."),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer; your answer is too high."),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer. If you're stuck, ..."),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            Verdict::parse("You gave an answer too recently; You have 1m 5s left to wait."),
+            Verdict::RateLimited { wait_secs: 65 }
+        );
+        assert_eq!(Verdict::parse("<html>"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn round_trips_ledger() {
+        let ledger = Ledger {
+            submissions: vec![
+                submission(1, "10", 100, Verdict::TooLow),
+                submission(1, "12", 200, Verdict::RateLimited { wait_secs: 30 }),
+            ],
+        };
+        let parsed = Ledger::parse(&ledger.serialize()).unwrap();
+        assert_eq!(parsed.submissions, ledger.submissions);
+    }
+
+    #[test]
+    fn rejects_known_and_bounded_answers() {
+        let ledger = Ledger {
+            submissions: vec![
+                submission(1, "10", 100, Verdict::TooLow),
+                submission(1, "20", 200, Verdict::TooHigh),
+                submission(1, "abc", 300, Verdict::Wrong),
+                submission(2, "5", 400, Verdict::Correct),
+            ],
+        };
+
+        assert_eq!(ledger.check_at(Part::One, &"15".into(), 1000), Ok(()));
+        assert_eq!(
+            ledger.check_at(Part::One, &"abc".into(), 1000),
+            Err(Rejection::KnownWrong(Verdict::Wrong))
+        );
+        assert_eq!(
+            ledger.check_at(Part::One, &"9".into(), 1000),
+            Err(Rejection::BelowTooLow("10".into()))
+        );
+        assert_eq!(
+            ledger.check_at(Part::One, &"20".into(), 1000),
+            Err(Rejection::KnownWrong(Verdict::TooHigh))
+        );
+        assert_eq!(
+            ledger.check_at(Part::One, &"25".into(), 1000),
+            Err(Rejection::AboveTooHigh("20".into()))
+        );
+        assert_eq!(
+            ledger.check_at(Part::Two, &"6".into(), 1000),
+            Err(Rejection::AlreadySolved("5".into()))
+        );
+    }
+
+    #[test]
+    fn rejects_while_rate_limited() {
+        let ledger = Ledger {
+            submissions: vec![submission(
+                1,
+                "10",
+                100,
+                Verdict::RateLimited { wait_secs: 60 },
+            )],
+        };
+        assert_eq!(
+            ledger.check_at(Part::One, &"11".into(), 130),
+            Err(Rejection::RateLimited { wait_secs: 30 })
+        );
+        assert_eq!(ledger.check_at(Part::One, &"11".into(), 160), Ok(()));
+    }
+}
@@ -0,0 +1,87 @@
+/// Module with the timeouts of a run, passed with `--timeout <duration>` for a whole day and
+/// `--part-timeout <duration>` for each part. With a timeout, parts run in a child process, so that a part
+/// that runs past one of them can be killed. It is reported as timed out and the run continues with the next part.
+use std::time::Duration;
+
+use crate::template::bench::parse_duration;
+
+/// Extra time a child of `cargo all` gets to report a timeout on its own before it is killed.
+pub const KILL_GRACE: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timeouts {
+    /// Limit for all parts of a day on one input together, including parsing and benchmarks.
+    pub day: Option<Duration>,
+    /// Limit for each part and for the step that both parts share, including its benchmark.
+    pub part: Option<Duration>,
+}
+
+impl Timeouts {
+    /// Reads the timeouts from the command line of a solution binary.
+    pub fn from_args(args: &[String]) -> Self {
+        let value_of = |name: &str| {
+            args.iter()
+                .position(|x| x == name)
+                .and_then(|i| args.get(i + 1))
+                .and_then(|x| parse_duration(x))
+        };
+
+        Self {
+            day: value_of("--timeout"),
+            part: value_of("--part-timeout"),
+        }
+    }
+
+    /// Converts the timeouts to the arguments understood by [`Timeouts::from_args`].
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+
+        if let Some(day) = self.day {
+            args.push("--timeout".into());
+            args.push(format!("{}ns", day.as_nanos()));
+        }
+
+        if let Some(part) = self.part {
+            args.push("--part-timeout".into());
+            args.push(format!("{}ns", part.as_nanos()));
+        }
+
+        args
+    }
+
+    pub fn is_set(&self) -> bool {
+        self.day.is_some() || self.part.is_some()
+    }
+
+    /// The longest a day can run on one input: the day timeout, or the part timeout for each part
+    /// and the step they share.
+    pub fn day_limit(&self) -> Option<Duration> {
+        let parts = self.part.map(|part| part * 3);
+        match (self.day, parts) {
+            (Some(day), Some(parts)) => Some(day.min(parts)),
+            (day, parts) => day.or(parts),
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Timeouts;
+    use std::time::Duration;
+
+    #[test]
+    fn round_trips_args() {
+        let timeouts = Timeouts {
+            day: Some(Duration::from_secs(30)),
+            part: Some(Duration::from_micros(1500)),
+        };
+        assert_eq!(Timeouts::from_args(&timeouts.to_args()), timeouts);
+        assert!(timeouts.is_set());
+        assert_eq!(timeouts.day_limit(), Some(Duration::from_micros(4500)));
+
+        let none = Timeouts::from_args(&["--time".into()]);
+        assert_eq!(none, Timeouts::default());
+        assert!(none.to_args().is_empty());
+        assert_eq!(none.day_limit(), None);
+    }
+}
@@ -0,0 +1,137 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The first year of advent of code.
+const FIRST_YEAR: u16 = 2015;
+
+/// Number of puzzles of an event, keyed by the first year the calendar applies to.
+/// Events since 2025 end on the 12th.
+const CALENDARS: [(u16, u8); 2] = [(FIRST_YEAR, 25), (2025, 12)];
+
+/// A valid year of advent of code (i.e. 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::Year;
+/// let year = Year::new(2023).unwrap();
+/// assert_eq!(year.to_string(), "2023")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// The number of puzzles of the year, i.e. its last day.
+    ///
+    /// ```
+    /// # use advent_of_code::Year;
+    /// assert_eq!(Year::new(2023).unwrap().day_count(), 25);
+    /// assert_eq!(Year::new(2025).unwrap().day_count(), 12);
+    /// ```
+    pub const fn day_count(self) -> u8 {
+        let mut i = CALENDARS.len() - 1;
+        while i > 0 && CALENDARS[i].0 > self.0 {
+            i -= 1;
+        }
+        CALENDARS[i].1
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl PartialEq<u16> for Year {
+    fn eq(&self, other: &u16) -> bool {
+        self.0.eq(other)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of advent of code, i.e. 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= 2015,
+            concat!(
+                "invalid year `",
+                $year,
+                "`, expecting a year of advent of code"
+            ),
+        );
+        $crate::Year::__new_unchecked($year)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2023".parse::<Year>().ok(), Some(year!(2023)));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("23".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn counts_days() {
+        assert_eq!(year!(2015).day_count(), 25);
+        assert_eq!(year!(2024).day_count(), 25);
+        assert_eq!(year!(2025).day_count(), 12);
+        assert_eq!(year!(2030).day_count(), 12);
+    }
+}
+
+/* -------------------------------------------------------------------------- */